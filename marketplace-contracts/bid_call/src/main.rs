@@ -6,41 +6,45 @@ compile_error!("target arch should be wasm32: compile with '--target wasm32-unkn
 
 extern crate alloc;
 
-use alloc::{
-    string::{String, ToString},
-    vec::Vec,
-};
-
-use casper_contract::{
-    contract_api::{account, runtime, storage, system},
-    unwrap_or_revert::UnwrapOrRevert,
-};
-use casper_types::{
-    contracts::NamedKeys, runtime_args, ApiError, ContractHash, Key, RuntimeArgs, URef, U256, U512,
-};
+use alloc::string::String;
+
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{runtime_args, ContractHash, RuntimeArgs, U256, U512};
+use session_utils::{get_optional_named_arg, get_trade_amount, insert_buy_purse};
 
 #[no_mangle]
 pub extern "C" fn call() {
-    let token_id: U256 = runtime::get_named_arg("token_id");
     let contract_hash: String = runtime::get_named_arg("bid_contract_hash");
     let marketplace_hash: String = runtime::get_named_arg("marketplace_hash");
     let amount: U512 = runtime::get_named_arg("amount");
 
+    let trade_amount = get_trade_amount(amount);
+
     let contract_hash_parsed: ContractHash =
         ContractHash::from_formatted_str(&marketplace_hash).unwrap();
 
-    let deposit_purse: URef = system::create_purse();
-
-    system::transfer_from_purse_to_purse(account::get_main_purse(), deposit_purse, amount, None)
-        .unwrap_or_revert();
-
-    runtime::call_contract(
-        contract_hash_parsed,
-        "place_bid",
-        runtime_args! {
-         "contract_hash" => contract_hash,
-         "token_id" => token_id,
-         "buy_purse" => deposit_purse
-        },
-    )
+    let mut args = runtime_args! {
+        "contract_hash" => contract_hash,
+        "amount" => trade_amount
+    };
+    insert_buy_purse(&mut args, amount);
+
+    // Tokens of hash identified CEP-78 collections are passed by hash
+    match get_optional_named_arg::<String>("token_hash") {
+        Some(token_hash) => args.insert("token_hash", token_hash),
+        None => args.insert("token_id", runtime::get_named_arg::<U256>("token_id")),
+    }
+    .unwrap_or_revert();
+
+    // Dutch auctions are won right away by paying current price, amount caps what is paid
+    // Buy now ends English auction at its fixed price, amount must match it
+    let entry_point = if get_optional_named_arg::<bool>("dutch_auction").unwrap_or(false) {
+        "buy_dutch_auction"
+    } else if get_optional_named_arg::<bool>("buy_now").unwrap_or(false) {
+        "buy_now"
+    } else {
+        "place_bid"
+    };
+
+    runtime::call_contract(contract_hash_parsed, entry_point, args)
 }
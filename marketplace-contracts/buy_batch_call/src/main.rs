@@ -0,0 +1,41 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{runtime_args, ContractHash, RuntimeArgs, URef, U256, U512};
+use session_utils::{create_buy_purse, get_optional_named_arg};
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let contract_hashes: Vec<String> = runtime::get_named_arg("buy_contract_hashes");
+    let token_ids: Vec<U256> = runtime::get_named_arg("token_ids");
+    let marketplace_hash: String = runtime::get_named_arg("marketplace_hash");
+    let amount: U512 = runtime::get_named_arg("amount");
+    let skip_unavailable: bool = runtime::get_named_arg("skip_unavailable");
+
+    let contract_hash_parsed = ContractHash::from_formatted_str(&marketplace_hash).unwrap();
+
+    // Single purse funds the whole sweep, unused part is refunded by marketplace
+    let deposit_purse: URef = create_buy_purse(amount);
+
+    let mut args = runtime_args! {
+        "contract_hashes" => contract_hashes,
+        "token_ids" => token_ids,
+        "buy_purse" => deposit_purse,
+        "skip_unavailable" => skip_unavailable
+    };
+
+    // Items of hash identified CEP-78 collections are passed by hash
+    if let Some(token_hashes) = get_optional_named_arg::<Vec<Option<String>>>("token_hashes") {
+        args.insert("token_hashes", token_hashes).unwrap_or_revert();
+    }
+
+    runtime::call_contract(contract_hash_parsed, "buy_listings_batch", args)
+}
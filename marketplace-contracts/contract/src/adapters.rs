@@ -0,0 +1,417 @@
+use alloc::{
+    boxed::Box,
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::fmt;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{self, FromBytes},
+    runtime_args, CLType, CLTyped, ContractHash, Key, RuntimeArgs, URef, U256,
+};
+
+use crate::{
+    collections::{get_collection_data, IdentifierMode, TokenStandard},
+    constants::{
+        ARG_TOKEN_HASH, ARG_TOKEN_HASHES, ARG_TOKEN_ID, ARG_TOKEN_IDS, KEY_ESCROWED_TOKENS,
+    },
+    error::Error,
+    utils::{contract_hash, contract_package_hash, get_optional_named_arg},
+};
+
+/// Token as passed by caller, numeric id or CEP-78 token hash
+pub enum TokenIdentifier {
+    Index(U256),
+    Hash(String),
+}
+
+impl TokenIdentifier {
+    /// Reads `token_hash` if present, `token_id` otherwise
+    pub fn from_args() -> TokenIdentifier {
+        match get_optional_named_arg::<String>(ARG_TOKEN_HASH) {
+            Some(token_hash) => TokenIdentifier::Hash(token_hash),
+            None => TokenIdentifier::Index(runtime::get_named_arg(ARG_TOKEN_ID)),
+        }
+    }
+
+    /// Reads batch `token_ids`, items that have an entry in optional `token_hashes` are passed
+    /// by hash and their id is ignored
+    pub fn batch_from_args() -> Vec<TokenIdentifier> {
+        let token_ids: Vec<U256> = runtime::get_named_arg(ARG_TOKEN_IDS);
+        let token_hashes: Vec<Option<String>> = get_optional_named_arg(ARG_TOKEN_HASHES)
+            .unwrap_or_else(|| vec![None; token_ids.len()]);
+
+        if token_hashes.len() != token_ids.len() {
+            runtime::revert(Error::BatchLengthMismatch);
+        }
+
+        token_ids
+            .into_iter()
+            .zip(token_hashes)
+            .map(|(token_id, token_hash)| TokenIdentifier::new(token_id, token_hash))
+            .collect()
+    }
+
+    pub fn new(token_id: U256, token_hash: Option<String>) -> TokenIdentifier {
+        match token_hash {
+            Some(token_hash) => TokenIdentifier::Hash(token_hash),
+            None => TokenIdentifier::Index(token_id),
+        }
+    }
+
+    /// Id and hash in the form batches take them, hash identified token has zero id
+    pub fn to_parts(&self) -> (U256, Option<String>) {
+        match self {
+            TokenIdentifier::Index(token_id) => (*token_id, None),
+            TokenIdentifier::Hash(token_hash) => (U256::zero(), Some(token_hash.clone())),
+        }
+    }
+
+    pub fn mode(&self) -> IdentifierMode {
+        match self {
+            TokenIdentifier::Index(_) => IdentifierMode::Ordinal,
+            TokenIdentifier::Hash(_) => IdentifierMode::Hash,
+        }
+    }
+
+    fn index(&self) -> U256 {
+        match self {
+            TokenIdentifier::Index(token_id) => *token_id,
+            TokenIdentifier::Hash(_) => runtime::revert(Error::InvalidTokenIdentifier),
+        }
+    }
+}
+
+// Ordinal ids keep their decimal form, so keys of existing listings don't change
+impl fmt::Display for TokenIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TokenIdentifier::Index(token_id) => write!(f, "{}", token_id),
+            TokenIdentifier::Hash(token_hash) => write!(f, "{}", token_hash),
+        }
+    }
+}
+
+/// Calls into a token contract, one implementation per supported standard
+pub trait TokenAdapter {
+    fn owner_of(&self, token_id: &TokenIdentifier) -> Key;
+
+    /// Checks if marketplace is approved to transfer owner's token
+    fn is_approved(&self, owner: Key, token_id: &TokenIdentifier) -> bool;
+
+    /// Checks if marketplace is operator for all of owner's tokens
+    fn is_approved_for_all(&self, owner: Key) -> bool;
+
+    fn transfer(&self, from: Key, to: Key, token_id: &TokenIdentifier);
+
+    fn metadata(&self, token_id: &TokenIdentifier) -> String;
+
+    fn contract_hash(&self) -> ContractHash;
+
+    /// Key under which the standard sees the marketplace as spender and holder
+    fn marketplace_address(&self) -> Key;
+}
+
+pub struct Cep47 {
+    pub contract_hash: ContractHash,
+}
+
+pub struct Cep78 {
+    pub contract_hash: ContractHash,
+    pub identifier_mode: IdentifierMode,
+}
+
+impl TokenAdapter for Cep47 {
+    fn owner_of(&self, token_id: &TokenIdentifier) -> Key {
+        runtime::call_contract::<Option<Key>>(
+            self.contract_hash,
+            "owner_of",
+            runtime_args! {
+              "token_id" => token_id.index()
+            },
+        )
+        .unwrap_or_revert()
+    }
+
+    fn is_approved(&self, owner: Key, token_id: &TokenIdentifier) -> bool {
+        let approved = runtime::call_contract::<Option<Key>>(
+            self.contract_hash,
+            "get_approved",
+            runtime_args! {
+            "owner" => owner,
+            "token_id" => token_id.index()
+            },
+        )
+        .unwrap_or_revert_with(Error::NeedsTransferApproval);
+        let approved_hash = approved.into_hash().unwrap_or_revert();
+
+        contract_package_hash().value() == approved_hash
+    }
+
+    // CEP-47 has no collection-wide approvals, every token is approved separately
+    fn is_approved_for_all(&self, _owner: Key) -> bool {
+        false
+    }
+
+    fn transfer(&self, from: Key, to: Key, token_id: &TokenIdentifier) {
+        runtime::call_contract::<()>(
+            self.contract_hash,
+            "transfer_from",
+            runtime_args! {
+              "sender" => from,
+              "recipient" => to,
+              "token_ids" => vec![token_id.index()],
+            },
+        )
+    }
+
+    fn metadata(&self, token_id: &TokenIdentifier) -> String {
+        let meta = runtime::call_contract::<Option<BTreeMap<String, String>>>(
+            self.contract_hash,
+            "token_meta",
+            runtime_args! {
+              "token_id" => token_id.index()
+            },
+        )
+        .unwrap_or_revert();
+
+        // Render as json object, same shape CEP-78 returns
+        let mut json = String::from("{");
+        for (index, (key, value)) in meta.iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+            push_json_string(&mut json, key);
+            json.push(':');
+            push_json_string(&mut json, value);
+        }
+        json.push('}');
+
+        json
+    }
+
+    fn contract_hash(&self) -> ContractHash {
+        self.contract_hash
+    }
+
+    fn marketplace_address(&self) -> Key {
+        contract_package_hash().into()
+    }
+}
+
+impl Cep78 {
+    /// Token args in the form the collection's identifier mode expects
+    fn token_args(&self, token_id: &TokenIdentifier) -> RuntimeArgs {
+        if token_id.mode() != self.identifier_mode {
+            runtime::revert(Error::InvalidTokenIdentifier);
+        }
+
+        match token_id {
+            TokenIdentifier::Index(index) => runtime_args! {
+                "token_id" => index.as_u64()
+            },
+            TokenIdentifier::Hash(token_hash) => runtime_args! {
+                "token_hash" => token_hash.to_string()
+            },
+        }
+    }
+}
+
+impl TokenAdapter for Cep78 {
+    fn owner_of(&self, token_id: &TokenIdentifier) -> Key {
+        runtime::call_contract::<Key>(self.contract_hash, "owner_of", self.token_args(token_id))
+    }
+
+    fn is_approved(&self, owner: Key, token_id: &TokenIdentifier) -> bool {
+        let mut args = self.token_args(token_id);
+        args.insert("owner", owner).unwrap_or_revert();
+
+        let approved =
+            runtime::call_contract::<Option<Key>>(self.contract_hash, "get_approved", args);
+
+        match approved.and_then(|approved| approved.into_hash()) {
+            Some(approved_hash) if contract_hash().value() == approved_hash => true,
+            // Operator approval covers every token of the owner
+            _ => self.is_approved_for_all(owner),
+        }
+    }
+
+    fn is_approved_for_all(&self, owner: Key) -> bool {
+        runtime::call_contract::<bool>(
+            self.contract_hash,
+            "is_approved_for_all",
+            runtime_args! {
+              "token_owner" => owner,
+              "operator" => self.marketplace_address()
+            },
+        )
+    }
+
+    fn transfer(&self, from: Key, to: Key, token_id: &TokenIdentifier) {
+        let mut args = self.token_args(token_id);
+        args.insert("source_key", from).unwrap_or_revert();
+        args.insert("target_key", to).unwrap_or_revert();
+
+        runtime::call_contract::<()>(self.contract_hash, "transfer", args)
+    }
+
+    fn metadata(&self, token_id: &TokenIdentifier) -> String {
+        runtime::call_contract::<String>(self.contract_hash, "metadata", self.token_args(token_id))
+    }
+
+    fn contract_hash(&self) -> ContractHash {
+        self.contract_hash
+    }
+
+    // Contract hash changes with every upgrade. Escrowed tokens are handed over to the new
+    // version, approvals given to a previous version stop working until sellers approve again.
+    fn marketplace_address(&self) -> Key {
+        contract_hash().into()
+    }
+}
+
+pub fn get_token_adapter(token_contract_hash: ContractHash) -> Box<dyn TokenAdapter> {
+    let collection = get_collection_data(token_contract_hash);
+
+    match collection.token_standard() {
+        TokenStandard::Cep47 => Box::new(Cep47 {
+            contract_hash: token_contract_hash,
+        }),
+        TokenStandard::Cep78 => Box::new(Cep78 {
+            contract_hash: token_contract_hash,
+            identifier_mode: collection.identifier_mode(),
+        }),
+    }
+}
+
+// Value returned by token contract, kept serialized
+struct ReturnedBytes(Vec<u8>);
+
+impl CLTyped for ReturnedBytes {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl FromBytes for ReturnedBytes {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        Ok((ReturnedBytes(bytes.to_vec()), &[]))
+    }
+}
+
+/// Tells standard of token contract from its `balance_of`. CEP-47 takes `owner` and returns
+/// U256, CEP-78 takes `token_owner` and returns u64, so both are asked for balance of an account
+/// no one controls and told apart by returned value.
+pub fn detect_token_standard(token_contract_hash: ContractHash) -> TokenStandard {
+    let nobody = Key::Account(AccountHash::default());
+    let balance = runtime::call_contract::<ReturnedBytes>(
+        token_contract_hash,
+        "balance_of",
+        runtime_args! {
+            "owner" => nobody,
+            "token_owner" => nobody
+        },
+    );
+
+    // Zero U256 is serialized as its zero length, u64 always takes 8 bytes
+    match balance.0.as_slice() {
+        [0] => TokenStandard::Cep47,
+        bytes if bytes.len() == 8 => TokenStandard::Cep78,
+        _ => runtime::revert(Error::UnknownTokenStandard),
+    }
+}
+
+/// Token held in escrow under contract hash, stored as collection, id and optional hash
+type EscrowedToken = (ContractHash, U256, Option<String>);
+
+pub fn get_escrowed_tokens_uref() -> URef {
+    match runtime::get_key(KEY_ESCROWED_TOKENS) {
+        Some(uref_key) => uref_key.into_uref().unwrap_or_revert(),
+        None => {
+            let uref = storage::new_uref(Vec::<EscrowedToken>::new());
+            runtime::put_key(KEY_ESCROWED_TOKENS, uref.into());
+            uref
+        }
+    }
+}
+
+// Tokens held under contract hash stay with the version that took them, so they are recorded
+// and handed over to the next version on upgrade
+fn held_by_contract_version(token: &dyn TokenAdapter) -> bool {
+    token.marketplace_address() == Key::from(contract_hash())
+}
+
+/// Number of tokens of a CEP-78 collection held by owner
+pub fn get_cep78_balance(token_contract_hash: ContractHash, owner: Key) -> u64 {
+    runtime::call_contract::<u64>(
+        token_contract_hash,
+        "balance_of",
+        runtime_args! {
+          "token_owner" => owner
+        },
+    )
+}
+
+pub fn get_escrowed_tokens() -> Vec<EscrowedToken> {
+    storage::read(get_escrowed_tokens_uref())
+        .unwrap_or_revert()
+        .unwrap_or_revert()
+}
+
+/// Moves token from owner into marketplace custody
+pub fn escrow_token(token: &dyn TokenAdapter, owner: Key, token_id: &TokenIdentifier) {
+    token.transfer(owner, token.marketplace_address(), token_id);
+
+    if held_by_contract_version(token) {
+        let (id, hash) = token_id.to_parts();
+        let mut escrowed_tokens = get_escrowed_tokens();
+        escrowed_tokens.push((token.contract_hash(), id, hash));
+        storage::write(get_escrowed_tokens_uref(), escrowed_tokens);
+    }
+}
+
+/// Moves token from marketplace custody to recipient
+pub fn release_token(token: &dyn TokenAdapter, recipient: Key, token_id: &TokenIdentifier) {
+    token.transfer(token.marketplace_address(), recipient, token_id);
+
+    if held_by_contract_version(token) {
+        let (id, hash) = token_id.to_parts();
+        let released_token = (token.contract_hash(), id, hash);
+        let mut escrowed_tokens = get_escrowed_tokens();
+        escrowed_tokens.retain(|escrowed_token| *escrowed_token != released_token);
+        storage::write(get_escrowed_tokens_uref(), escrowed_tokens);
+    }
+}
+
+/// Transfers every token held under this version's contract hash to the next version. Record
+/// is shared by both versions and stays as is, it now lists tokens the next version holds.
+pub fn transfer_escrowed_tokens(next_contract_hash: ContractHash) {
+    for (token_contract_hash, id, hash) in get_escrowed_tokens() {
+        let token = get_token_adapter(token_contract_hash);
+        token.transfer(
+            contract_hash().into(),
+            next_contract_hash.into(),
+            &TokenIdentifier::new(id, hash),
+        );
+    }
+}
+
+fn push_json_string(json: &mut String, value: &str) {
+    json.push('"');
+    for character in value.chars() {
+        match character {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            _ => json.push(character),
+        }
+    }
+    json.push('"');
+}
@@ -0,0 +1,66 @@
+use alloc::string::String;
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{ContractHash, Key, URef};
+
+use crate::{
+    adapters::TokenIdentifier,
+    error::Error,
+    utils::{get_dictionary_uref, get_listing_key},
+};
+
+pub fn get_blocked_tokens_dictionary() -> URef {
+    get_dictionary_uref("blocked_tokens")
+}
+
+pub fn get_blocked_accounts_dictionary() -> URef {
+    get_dictionary_uref("blocked_accounts")
+}
+
+fn get_blocked_account_key(account: Key) -> String {
+    hex::encode(account.into_account().unwrap_or_revert().value())
+}
+
+pub fn is_token_blocked(token_contract_hash: ContractHash, token_id: &TokenIdentifier) -> bool {
+    let key = get_listing_key(token_contract_hash, token_id);
+    match storage::dictionary_get::<bool>(get_blocked_tokens_dictionary(), &key) {
+        Ok(Some(blocked)) => blocked,
+        _ => false,
+    }
+}
+
+pub fn set_token_blocked(
+    token_contract_hash: ContractHash,
+    token_id: &TokenIdentifier,
+    blocked: bool,
+) {
+    let key = get_listing_key(token_contract_hash, token_id);
+    storage::dictionary_put(get_blocked_tokens_dictionary(), &key, blocked)
+}
+
+pub fn is_account_blocked(account: Key) -> bool {
+    let key = get_blocked_account_key(account);
+    match storage::dictionary_get::<bool>(get_blocked_accounts_dictionary(), &key) {
+        Ok(Some(blocked)) => blocked,
+        _ => false,
+    }
+}
+
+pub fn set_account_blocked(account: Key, blocked: bool) {
+    let key = get_blocked_account_key(account);
+    storage::dictionary_put(get_blocked_accounts_dictionary(), &key, blocked)
+}
+
+pub fn require_token_not_blocked(token_contract_hash: ContractHash, token_id: &TokenIdentifier) {
+    if is_token_blocked(token_contract_hash, token_id) {
+        runtime::revert(Error::TokenBlocked);
+    }
+}
+
+pub fn require_account_not_blocked(account: Key) {
+    if is_account_blocked(account) {
+        runtime::revert(Error::AccountBlocked);
+    }
+}
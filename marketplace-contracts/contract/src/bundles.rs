@@ -0,0 +1,66 @@
+use alloc::{string::ToString, vec::Vec};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::URef;
+
+use crate::{
+    adapters::TokenIdentifier, constants::KEY_BUNDLE_COUNTER, error::Error, structs::BundleData,
+    utils::get_dictionary_uref,
+};
+
+pub fn get_bundle_dictionary() -> URef {
+    get_dictionary_uref("bundles")
+}
+
+pub fn get_bundle_counter_uref() -> URef {
+    match runtime::get_key(KEY_BUNDLE_COUNTER) {
+        Some(uref_key) => uref_key.into_uref().unwrap_or_revert(),
+        None => {
+            let uref = storage::new_uref(0u64);
+            runtime::put_key(KEY_BUNDLE_COUNTER, uref.into());
+            uref
+        }
+    }
+}
+
+// Bundle ids start at 1 and are never reused
+pub fn next_bundle_id() -> u64 {
+    let counter_uref = get_bundle_counter_uref();
+    let bundle_id: u64 = storage::read::<u64>(counter_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert()
+        + 1;
+    storage::write(counter_uref, bundle_id);
+
+    bundle_id
+}
+
+pub fn get_bundle_data(bundle_id: u64) -> BundleData {
+    match storage::dictionary_get(get_bundle_dictionary(), &bundle_id.to_string()) {
+        Ok(Some(bundle)) => bundle,
+        _ => runtime::revert(Error::BundleDoesntExist),
+    }
+}
+
+pub fn set_bundle_data(bundle_id: u64, bundle: BundleData) {
+    storage::dictionary_put(get_bundle_dictionary(), &bundle_id.to_string(), bundle)
+}
+
+pub fn clear_bundle_data(bundle_id: u64) {
+    storage::dictionary_put(
+        get_bundle_dictionary(),
+        &bundle_id.to_string(),
+        None::<BundleData>,
+    )
+}
+
+pub fn get_bundle_token_ids(bundle: &BundleData) -> Vec<TokenIdentifier> {
+    bundle
+        .token_ids
+        .iter()
+        .zip(bundle.token_hashes.iter())
+        .map(|(token_id, token_hash)| TokenIdentifier::new(*token_id, token_hash.clone()))
+        .collect()
+}
@@ -0,0 +1,154 @@
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{ContractHash, URef};
+
+use crate::{
+    adapters::{detect_token_standard, TokenIdentifier},
+    constants::KEY_CURATED_MODE,
+    error::Error,
+    structs::CollectionData,
+    utils::get_dictionary_uref,
+};
+
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq)]
+pub enum TokenStandard {
+    Cep47 = 0,
+    Cep78 = 1,
+}
+
+impl TokenStandard {
+    pub fn from_u8(value: u8) -> TokenStandard {
+        match value {
+            0 => TokenStandard::Cep47,
+            1 => TokenStandard::Cep78,
+            _ => runtime::revert(Error::UnknownTokenStandard),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq)]
+pub enum IdentifierMode {
+    Ordinal = 0,
+    Hash = 1,
+}
+
+impl IdentifierMode {
+    pub fn from_u8(value: u8) -> IdentifierMode {
+        match value {
+            0 => IdentifierMode::Ordinal,
+            1 => IdentifierMode::Hash,
+            _ => runtime::revert(Error::UnknownIdentifierMode),
+        }
+    }
+}
+
+impl CollectionData {
+    pub fn token_standard(&self) -> TokenStandard {
+        TokenStandard::from_u8(self.token_standard)
+    }
+
+    pub fn identifier_mode(&self) -> IdentifierMode {
+        IdentifierMode::from_u8(self.identifier_mode)
+    }
+}
+
+pub fn get_curated_mode_uref() -> URef {
+    match runtime::get_key(KEY_CURATED_MODE) {
+        Some(uref_key) => uref_key.into_uref().unwrap_or_revert(),
+        None => {
+            let uref = storage::new_uref(false);
+            runtime::put_key(KEY_CURATED_MODE, uref.into());
+            uref
+        }
+    }
+}
+
+pub fn get_curated_mode() -> bool {
+    storage::read(get_curated_mode_uref())
+        .unwrap_or_revert()
+        .unwrap_or_revert()
+}
+
+/// In curated mode only collections verified by admin can be listed, bought, offered on or auctioned
+pub fn is_tradable(token_contract_hash: ContractHash) -> bool {
+    if !get_curated_mode() {
+        return true;
+    }
+
+    match find_collection_data(token_contract_hash) {
+        Some(collection) => collection.verified,
+        None => false,
+    }
+}
+
+/// Checked again at settlement since verification can be revoked after an item is put up
+pub fn require_tradable(token_contract_hash: ContractHash) {
+    if !is_tradable(token_contract_hash) {
+        runtime::revert(Error::CollectionNotVerified);
+    }
+}
+
+pub fn get_collections_dictionary() -> URef {
+    get_dictionary_uref("collections")
+}
+
+pub fn set_collection_data(token_contract_hash: ContractHash, collection: CollectionData) {
+    storage::dictionary_put(
+        get_collections_dictionary(),
+        &token_contract_hash.to_formatted_string(),
+        collection,
+    )
+}
+
+fn find_collection_data(token_contract_hash: ContractHash) -> Option<CollectionData> {
+    let key = token_contract_hash.to_formatted_string();
+    match storage::dictionary_get::<CollectionData>(get_collections_dictionary(), &key) {
+        Ok(collection) => collection,
+        Err(_) => None,
+    }
+}
+
+pub fn is_collection_recorded(token_contract_hash: ContractHash) -> bool {
+    find_collection_data(token_contract_hash).is_some()
+}
+
+pub fn get_collection_data(token_contract_hash: ContractHash) -> CollectionData {
+    match find_collection_data(token_contract_hash) {
+        Some(collection) => collection,
+        None => detect_collection(token_contract_hash, &TokenIdentifier::from_args()),
+    }
+}
+
+/// Records standard of a collection that wasn't registered by admin, as detected from token
+/// contract itself. Collections it can't tell apart are rejected until admin registers them.
+/// Identifier mode follows the way the token was passed, by hash or by id.
+fn detect_collection(
+    token_contract_hash: ContractHash,
+    token_id: &TokenIdentifier,
+) -> CollectionData {
+    let collection = CollectionData {
+        token_standard: detect_token_standard(token_contract_hash) as u8,
+        identifier_mode: token_id.mode() as u8,
+        verified: false,
+    };
+    set_collection_data(token_contract_hash, collection);
+
+    collection
+}
+
+/// Detects unknown collections of a batch before any of its tokens is touched
+pub fn detect_batch_collections(contract_hashes: &[ContractHash], token_ids: &[TokenIdentifier]) {
+    if contract_hashes.len() != token_ids.len() {
+        runtime::revert(Error::BatchLengthMismatch);
+    }
+
+    for (contract_hash, token_id) in contract_hashes.iter().zip(token_ids) {
+        if find_collection_data(*contract_hash).is_none() {
+            detect_collection(*contract_hash, token_id);
+        }
+    }
+}
@@ -8,17 +8,76 @@ pub const CONTRACT_ACCESS_UREF: &str = "mystra_marketplace_access_uref";
 pub const ARG_DURATION_MINUTES: &str = "duration_minutes";
 pub const ARG_PRICE: &str = "price";
 pub const ARG_TOKEN_ID: &str = "token_id";
+pub const ARG_TOKEN_HASH: &str = "token_hash";
 pub const ARG_TOKEN_CONTRACT: &str = "contract_hash";
 pub const ARG_BUY_PURSE: &str = "buy_purse";
 pub const ARG_ROYALTIES_PERCENTAGE: &str = "royalties_percentage";
 pub const ARG_OFFERER: &str = "offerer";
 pub const ARG_CREATOR: &str = "creator";
 pub const ARG_TOKEN_STANDARD: &str = "token_standard";
+pub const ARG_AMOUNT: &str = "amount";
+pub const ARG_FEE_BASIS_POINTS: &str = "fee_basis_points";
+pub const ARG_FEE_RECIPIENT: &str = "fee_recipient";
+pub const ARG_ROLE: &str = "role";
+pub const ARG_ACCOUNT: &str = "account";
+pub const ARG_NEW_ADMIN: &str = "new_admin";
+pub const ARG_FEATURES: &str = "features";
+pub const ARG_IDENTIFIER_MODE: &str = "identifier_mode";
+pub const ARG_PAYMENT_TOKEN: &str = "payment_token";
+pub const ARG_VERIFIED: &str = "verified";
+pub const ARG_CURATED: &str = "curated";
+pub const ARG_TOKEN_CONTRACTS: &str = "contract_hashes";
+pub const ARG_TOKEN_IDS: &str = "token_ids";
+pub const ARG_TOKEN_HASHES: &str = "token_hashes";
+pub const ARG_PRICES: &str = "prices";
+pub const ARG_DURATIONS: &str = "durations";
+pub const ARG_SKIP_UNAVAILABLE: &str = "skip_unavailable";
+pub const ARG_BUNDLE_ID: &str = "bundle_id";
+pub const ARG_FLOOR_PRICE: &str = "floor_price";
+pub const ARG_STEP_MINUTES: &str = "step_minutes";
+pub const ARG_RESERVE_PRICE: &str = "reserve_price";
+pub const ARG_RESERVE_SALT: &str = "reserve_salt";
+pub const ARG_RESERVE_COMMITMENT: &str = "reserve_commitment";
+pub const ARG_MIN_INCREMENT: &str = "min_increment";
+pub const ARG_MIN_INCREMENT_BASIS_POINTS: &str = "min_increment_basis_points";
+pub const ARG_EXTENSION_WINDOW_MINUTES: &str = "extension_window_minutes";
+pub const ARG_EXTENSION_MINUTES: &str = "extension_minutes";
+pub const ARG_MAX_EXTENSIONS: &str = "max_extensions";
+pub const ARG_BUY_NOW_PRICE: &str = "buy_now_price";
+pub const ARG_START_TIME: &str = "start_time";
+pub const ARG_PREVIOUS_CONTRACT_HASH: &str = "previous_contract_hash";
 
 // Purse names
 pub const PURSE_OFFERS: &str = "offers_purse";
 pub const PURSE_AUCTIONS: &str = "auctions_purse";
-pub const PURSE_REUSABLE : &str = "reusable_purse";
+pub const PURSE_ESCROW: &str = "escrow_purse";
 
 // Keys
 pub const KEY_INSTALLER: &str = "installer";
+pub const KEY_PENDING_ADMIN: &str = "pending_admin";
+pub const KEY_MARKETPLACE_FEE: &str = "marketplace_fee";
+pub const KEY_FEE_RECIPIENT: &str = "fee_recipient";
+pub const KEY_PAUSED_FEATURES: &str = "paused_features";
+pub const KEY_CURATED_MODE: &str = "curated_mode";
+pub const KEY_BUNDLE_COUNTER: &str = "bundle_counter";
+pub const KEY_AUCTION_EXTENSION: &str = "auction_extension";
+pub const KEY_ESCROWED_TOKENS: &str = "escrowed_tokens";
+
+// Fees
+pub const DEFAULT_MARKETPLACE_FEE_BASIS_POINTS: u64 = 1000;
+pub const MAX_BASIS_POINTS: u64 = 10000;
+
+// Auction anti-sniping defaults
+pub const DEFAULT_EXTENSION_WINDOW_MINUTES: u64 = 10;
+pub const DEFAULT_EXTENSION_MINUTES: u64 = 10;
+pub const DEFAULT_MAX_EXTENSIONS: u64 = 10;
+pub const MAX_EXTENSION_MINUTES: u64 = 1440;
+
+// Seller has a day after auction end to reveal reserve, then unrevealed reserve counts as met
+pub const RESERVE_REVEAL_MINUTES: u64 = 1440;
+
+
+// Pausable features, combined as bit flags
+pub const FEATURE_LISTINGS: u8 = 1;
+pub const FEATURE_OFFERS: u8 = 2;
+pub const FEATURE_AUCTIONS: u8 = 4;
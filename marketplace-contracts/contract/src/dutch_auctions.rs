@@ -0,0 +1,44 @@
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{URef, U512};
+
+use crate::{error::Error, structs::DutchAuctionData, utils::get_dictionary_uref};
+
+pub fn get_dutch_auction_dictionary() -> URef {
+    get_dictionary_uref("dutch_auctions")
+}
+
+pub fn get_dutch_auction_data(key: &str) -> DutchAuctionData {
+    match storage::dictionary_get(get_dutch_auction_dictionary(), key) {
+        Ok(Some(auction)) => auction,
+        _ => runtime::revert(Error::AuctionDoesntExist),
+    }
+}
+
+pub fn set_dutch_auction_data(key: &str, auction: DutchAuctionData) {
+    storage::dictionary_put(get_dutch_auction_dictionary(), key, auction)
+}
+
+pub fn clear_dutch_auction_data(key: &str) {
+    storage::dictionary_put(
+        get_dutch_auction_dictionary(),
+        key,
+        None::<DutchAuctionData>,
+    )
+}
+
+// Elapsed time is rounded down to whole steps, so stepwise price drops at the start of each step
+pub fn get_dutch_auction_price(auction: &DutchAuctionData, current_time: u64) -> U512 {
+    if current_time >= auction.end_time {
+        return auction.floor_price;
+    }
+
+    let duration = auction.end_time - auction.start_time;
+    let mut elapsed = current_time.saturating_sub(auction.start_time);
+    if auction.step_interval > 0 {
+        elapsed -= elapsed % auction.step_interval;
+    }
+
+    let decay = (auction.start_price - auction.floor_price) * U512::from(elapsed)
+        / U512::from(duration);
+    auction.start_price - decay
+}
@@ -1,9 +1,11 @@
-use alloc::{string::String, vec};
+use alloc::{string::String, vec, vec::Vec};
 use casper_types::{
-    CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Key, Parameter,
-    URef, U256, U512,
+    CLType, CLTyped, ContractHash, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Key,
+    Parameter, URef, U256, U512,
 };
 
+use crate::structs::{BundleData, DutchAuctionData, Fields, RoyaltyData};
+
 pub fn get_entry_points() -> EntryPoints {
     let mut entry_points = EntryPoints::new();
 
@@ -32,9 +34,103 @@ pub fn get_entry_points() -> EntryPoints {
     ));
 
     entry_points.add_entry_point(EntryPoint::new(
-        "get_reusable_purse",
-        vec![],
-        CLType::URef,
+        "create_listings_batch",
+        vec![
+            Parameter::new("contract_hashes", Vec::<String>::cl_type()),
+            Parameter::new("token_ids", Vec::<U256>::cl_type()),
+            Parameter::new("prices", Vec::<U512>::cl_type()),
+            Parameter::new("durations", Vec::<u64>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "create_bundle",
+        vec![
+            Parameter::new("contract_hashes", Vec::<String>::cl_type()),
+            Parameter::new("token_ids", Vec::<U256>::cl_type()),
+            Parameter::new("price", U512::cl_type()),
+            Parameter::new("duration_minutes", u64::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "cancel_bundle",
+        vec![Parameter::new("bundle_id", u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "buy_bundle",
+        vec![
+            Parameter::new("bundle_id", u64::cl_type()),
+            Parameter::new("amount", U512::cl_type()),
+            Parameter::new("buy_purse", URef::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_bundle",
+        vec![Parameter::new("bundle_id", u64::cl_type())],
+        BundleData::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "buy_listings_batch",
+        vec![
+            Parameter::new("contract_hashes", Vec::<String>::cl_type()),
+            Parameter::new("token_ids", Vec::<U256>::cl_type()),
+            Parameter::new("buy_purse", URef::cl_type()),
+            Parameter::new("skip_unavailable", bool::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "cancel_listings_batch",
+        vec![
+            Parameter::new("contract_hashes", Vec::<String>::cl_type()),
+            Parameter::new("token_ids", Vec::<U256>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "deposit",
+        vec![Parameter::new("buy_purse", URef::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "withdraw",
+        vec![Parameter::new("amount", Option::<U512>::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_pending_balance",
+        vec![Parameter::new("account", Key::cl_type())],
+        U512::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
@@ -45,6 +141,7 @@ pub fn get_entry_points() -> EntryPoints {
             Parameter::new("contract_hash", String::cl_type()),
             Parameter::new("token_id", U256::cl_type()),
             Parameter::new("buy_purse", URef::cl_type()),
+            Parameter::new("amount", U512::cl_type()),
         ],
         <()>::cl_type(),
         EntryPointAccess::Public,
@@ -59,12 +156,32 @@ pub fn get_entry_points() -> EntryPoints {
         EntryPointType::Contract,
     ));
 
+    entry_points.add_entry_point(EntryPoint::new(
+        "migrate",
+        vec![Parameter::new(
+            "previous_contract_hash",
+            ContractHash::cl_type(),
+        )],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "hand_over_escrowed_tokens",
+        vec![],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
     entry_points.add_entry_point(EntryPoint::new(
         "make_offer",
         vec![
             Parameter::new("contract_hash", String::cl_type()),
             Parameter::new("token_id", U256::cl_type()),
             Parameter::new("buy_purse", URef::cl_type()),
+            Parameter::new("amount", U512::cl_type()),
             Parameter::new("duration_minutes", u64::cl_type()),
         ],
         <()>::cl_type(),
@@ -119,12 +236,240 @@ pub fn get_entry_points() -> EntryPoints {
         EntryPointType::Contract,
     ));
 
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_marketplace_fee",
+        vec![Parameter::new("fee_basis_points", u64::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_fee_recipient",
+        vec![Parameter::new("fee_recipient", Option::<Key>::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_collection_fee",
+        vec![
+            Parameter::new("contract_hash", String::cl_type()),
+            Parameter::new("fee_basis_points", Option::<u64>::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "register_collection",
+        vec![
+            Parameter::new("contract_hash", String::cl_type()),
+            Parameter::new("token_standard", u8::cl_type()),
+            Parameter::new("identifier_mode", u8::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_curated_mode",
+        vec![Parameter::new("curated", bool::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "block_token",
+        vec![
+            Parameter::new("contract_hash", String::cl_type()),
+            Parameter::new("token_id", U256::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "unblock_token",
+        vec![
+            Parameter::new("contract_hash", String::cl_type()),
+            Parameter::new("token_id", U256::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "block_account",
+        vec![Parameter::new("account", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "unblock_account",
+        vec![Parameter::new("account", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "force_delist",
+        vec![
+            Parameter::new("contract_hash", String::cl_type()),
+            Parameter::new("token_id", U256::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_collection_fee",
+        vec![Parameter::new("contract_hash", String::cl_type())],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_marketplace_fee",
+        vec![],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_listing",
+        vec![
+            Parameter::new("contract_hash", String::cl_type()),
+            Parameter::new("token_id", U256::cl_type()),
+        ],
+        Fields::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_offer",
+        vec![
+            Parameter::new("contract_hash", String::cl_type()),
+            Parameter::new("token_id", U256::cl_type()),
+            Parameter::new("offerer", String::cl_type()),
+        ],
+        Fields::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_auction",
+        vec![
+            Parameter::new("contract_hash", String::cl_type()),
+            Parameter::new("token_id", U256::cl_type()),
+        ],
+        Fields::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_token_metadata",
+        vec![
+            Parameter::new("contract_hash", String::cl_type()),
+            Parameter::new("token_id", U256::cl_type()),
+        ],
+        String::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_royalty",
+        vec![Parameter::new("contract_hash", String::cl_type())],
+        Option::<RoyaltyData>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "grant_role",
+        vec![
+            Parameter::new("role", u8::cl_type()),
+            Parameter::new("account", Key::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "revoke_role",
+        vec![
+            Parameter::new("role", u8::cl_type()),
+            Parameter::new("account", Key::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "propose_admin",
+        vec![Parameter::new("new_admin", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "accept_admin",
+        vec![],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "cancel_admin_proposal",
+        vec![],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "pause",
+        vec![Parameter::new("features", u8::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "unpause",
+        vec![Parameter::new("features", u8::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
     entry_points.add_entry_point(EntryPoint::new(
         "place_bid",
         vec![
             Parameter::new("contract_hash", String::cl_type()),
             Parameter::new("token_id", U256::cl_type()),
             Parameter::new("buy_purse", URef::cl_type()),
+            Parameter::new("amount", U512::cl_type()),
         ],
         <()>::cl_type(),
         EntryPointAccess::Public,
@@ -142,5 +487,90 @@ pub fn get_entry_points() -> EntryPoints {
         EntryPointType::Contract,
     ));
 
+    entry_points.add_entry_point(EntryPoint::new(
+        "buy_now",
+        vec![
+            Parameter::new("contract_hash", String::cl_type()),
+            Parameter::new("token_id", U256::cl_type()),
+            Parameter::new("buy_purse", URef::cl_type()),
+            Parameter::new("amount", U512::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_auction_extension",
+        vec![
+            Parameter::new("extension_window_minutes", u64::cl_type()),
+            Parameter::new("extension_minutes", u64::cl_type()),
+            Parameter::new("max_extensions", u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "start_dutch_auction",
+        vec![
+            Parameter::new("contract_hash", String::cl_type()),
+            Parameter::new("token_id", U256::cl_type()),
+            Parameter::new("price", U512::cl_type()),
+            Parameter::new("floor_price", U512::cl_type()),
+            Parameter::new("duration_minutes", u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "buy_dutch_auction",
+        vec![
+            Parameter::new("contract_hash", String::cl_type()),
+            Parameter::new("token_id", U256::cl_type()),
+            Parameter::new("buy_purse", URef::cl_type()),
+            Parameter::new("amount", U512::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "cancel_dutch_auction",
+        vec![
+            Parameter::new("contract_hash", String::cl_type()),
+            Parameter::new("token_id", U256::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_dutch_auction",
+        vec![
+            Parameter::new("contract_hash", String::cl_type()),
+            Parameter::new("token_id", U256::cl_type()),
+        ],
+        DutchAuctionData::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_current_price",
+        vec![
+            Parameter::new("contract_hash", String::cl_type()),
+            Parameter::new("token_id", U256::cl_type()),
+        ],
+        U512::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
     entry_points
 }
@@ -20,7 +20,40 @@ pub enum Error {
     AuctionNotFinished = 15,
     CallerNotInstaller = 16,
     TokenAlreadyOnListing = 17,
-    OfferExpired = 18
+    OfferExpired = 18,
+    PaymentAmountMismatch = 19,
+    InvalidFee = 20,
+    MissingRole = 21,
+    InvalidRole = 22,
+    NoAdminProposal = 23,
+    CallerNotPendingAdmin = 24,
+    MarketplacePaused = 25,
+    UnknownTokenStandard = 26,
+    UnknownIdentifierMode = 27,
+    InvalidTokenIdentifier = 28,
+    PaymentAmountOverflow = 29,
+    CollectionNotVerified = 30,
+    TokenBlocked = 31,
+    AccountBlocked = 32,
+    TokenNotBlocked = 33,
+    BatchLengthMismatch = 34,
+    BundleDoesntExist = 35,
+    EmptyBundle = 36,
+    InvalidPriceRange = 37,
+    InvalidDuration = 38,
+    BidIncrementTooLow = 39,
+    InvalidBidIncrement = 40,
+    InvalidReservePrice = 41,
+    InvalidBuyNowPrice = 42,
+    BuyNowUnavailable = 43,
+    NotStarted = 44,
+    InvalidFeeRecipient = 45,
+    FeeExceedsPrice = 46,
+    DuplicateBundleItem = 47,
+    InvalidExtension = 48,
+    TokensInEscrow = 49,
+    PaymentTokenMismatch = 50,
+    ReserveNotRevealed = 51
 }
 
 impl From<Error> for ApiError {
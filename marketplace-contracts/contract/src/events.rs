@@ -1,14 +1,28 @@
-use alloc::string::String;
-use casper_event_standard::{Event, Schemas};
-use casper_types::{ContractHash, Key, U512};
+use alloc::{
+    collections::BTreeSet,
+    string::{String, ToString},
+    vec::Vec,
+};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_event_standard::{Event, Schemas, EVENTS_DICT, EVENTS_LENGTH, EVENTS_SCHEMA};
+use casper_types::{
+    bytesrepr::{Bytes, FromBytes},
+    ContractHash, Key, URef, U256, U512,
+};
 
 #[derive(Event)]
 pub struct NewListing {
     pub seller: Key,
     pub contract_hash: ContractHash,
     pub token_id: String,
+    pub identifier_mode: u8,
+    pub payment_token: Option<ContractHash>,
     pub price: U512,
     pub timestamp: u64,
+    pub start_time: u64,
     pub expiration_date: u64,
 }
 
@@ -18,6 +32,8 @@ pub struct ListingBought {
     pub buyer: Key,
     pub contract_hash: ContractHash,
     pub token_id: String,
+    pub identifier_mode: u8,
+    pub payment_token: Option<ContractHash>,
     pub price: U512,
     pub timestamp: u64,
 }
@@ -27,6 +43,38 @@ pub struct ListingCancelled {
     pub seller: Key,
     pub contract_hash: ContractHash,
     pub token_id: String,
+    pub identifier_mode: u8,
+    pub payment_token: Option<ContractHash>,
+    pub timestamp: u64,
+}
+
+#[derive(Event)]
+pub struct BundleCreated {
+    pub bundle_id: u64,
+    pub seller: Key,
+    pub contract_hashes: Vec<ContractHash>,
+    pub token_ids: Vec<U256>,
+    pub token_hashes: Vec<Option<String>>,
+    pub payment_token: Option<ContractHash>,
+    pub price: U512,
+    pub timestamp: u64,
+    pub expiration_date: u64,
+}
+
+#[derive(Event)]
+pub struct BundleBought {
+    pub bundle_id: u64,
+    pub seller: Key,
+    pub buyer: Key,
+    pub payment_token: Option<ContractHash>,
+    pub price: U512,
+    pub timestamp: u64,
+}
+
+#[derive(Event)]
+pub struct BundleCancelled {
+    pub bundle_id: u64,
+    pub seller: Key,
     pub timestamp: u64,
 }
 
@@ -35,6 +83,8 @@ pub struct NewOffer {
     pub buyer: Key,
     pub contract_hash: ContractHash,
     pub token_id: String,
+    pub identifier_mode: u8,
+    pub payment_token: Option<ContractHash>,
     pub price: U512,
     pub timestamp: u64,
     pub expiration_date: u64
@@ -45,6 +95,8 @@ pub struct OfferCancelled {
     pub buyer: Key,
     pub contract_hash: ContractHash,
     pub token_id: String,
+    pub identifier_mode: u8,
+    pub payment_token: Option<ContractHash>,
     pub timestamp: u64,
 }
 
@@ -55,6 +107,8 @@ pub struct OfferAccepted {
     pub seller: Key,
     pub contract_hash: ContractHash,
     pub token_id: String,
+    pub identifier_mode: u8,
+    pub payment_token: Option<ContractHash>,
     pub price: U512,
     pub timestamp: u64,
 }
@@ -64,8 +118,12 @@ pub struct AuctionStarted {
     pub seller: Key,
     pub contract_hash: ContractHash,
     pub token_id: String,
+    pub identifier_mode: u8,
+    pub payment_token: Option<ContractHash>,
     pub starting_price: U512,
+    pub buy_now_price: Option<U512>,
     pub timestamp: u64,
+    pub start_time: u64,
     pub end_date: u64
 }
 
@@ -75,7 +133,10 @@ pub struct AuctionEnded {
     pub winner: Key,
     pub contract_hash: ContractHash,
     pub token_id: String,
+    pub identifier_mode: u8,
+    pub payment_token: Option<ContractHash>,
     pub ending_price: U512,
+    pub buy_now: bool,
     pub timestamp: u64,
 }
 
@@ -86,8 +147,56 @@ pub struct Bid {
     pub contract_hash: ContractHash,
     pub bid_price: U512,
     pub token_id: String,
+    pub identifier_mode: u8,
+    pub payment_token: Option<ContractHash>,
+    pub timestamp: u64,
+    pub new_end_timestamp: u64,
+}
+
+#[derive(Event)]
+pub struct AuctionExtensionChanged {
+    pub window: u64,
+    pub length: u64,
+    pub max_extensions: u64,
+    pub sender: Key,
+    pub timestamp: u64,
+}
+
+#[derive(Event)]
+pub struct DutchAuctionStarted {
+    pub seller: Key,
+    pub contract_hash: ContractHash,
+    pub token_id: String,
+    pub identifier_mode: u8,
+    pub payment_token: Option<ContractHash>,
+    pub start_price: U512,
+    pub floor_price: U512,
+    pub step_interval: u64,
+    pub timestamp: u64,
+    pub start_time: u64,
+    pub end_date: u64,
+}
+
+#[derive(Event)]
+pub struct DutchAuctionBought {
+    pub seller: Key,
+    pub buyer: Key,
+    pub contract_hash: ContractHash,
+    pub token_id: String,
+    pub identifier_mode: u8,
+    pub payment_token: Option<ContractHash>,
+    pub price: U512,
+    pub timestamp: u64,
+}
+
+#[derive(Event)]
+pub struct DutchAuctionCancelled {
+    pub seller: Key,
+    pub contract_hash: ContractHash,
+    pub token_id: String,
+    pub identifier_mode: u8,
+    pub payment_token: Option<ContractHash>,
     pub timestamp: u64,
-    pub new_end_timestamp: u64
 }
 
 #[derive(Event)]
@@ -97,21 +206,250 @@ pub struct RoyaltySet {
     pub percentage: u64,
 }
 
-pub fn init_events() {
-    let schemas = Schemas::new()
+#[derive(Event)]
+pub struct MarketplaceFeeChanged {
+    pub fee_basis_points: u64,
+    pub fee_recipient: Key,
+    pub timestamp: u64,
+}
+
+#[derive(Event)]
+pub struct CollectionFeeSet {
+    pub contract_hash: ContractHash,
+    pub fee_basis_points: Option<u64>,
+    pub timestamp: u64,
+}
+
+#[derive(Event)]
+pub struct RoleGranted {
+    pub role: u8,
+    pub account: Key,
+    pub sender: Key,
+    pub timestamp: u64,
+}
+
+#[derive(Event)]
+pub struct RoleRevoked {
+    pub role: u8,
+    pub account: Key,
+    pub sender: Key,
+    pub timestamp: u64,
+}
+
+#[derive(Event)]
+pub struct AdminProposed {
+    pub current_admin: Key,
+    pub proposed_admin: Key,
+    pub timestamp: u64,
+}
+
+#[derive(Event)]
+pub struct AdminProposalCancelled {
+    pub current_admin: Key,
+    pub proposed_admin: Key,
+    pub timestamp: u64,
+}
+
+#[derive(Event)]
+pub struct AdminTransferred {
+    pub previous_admin: Key,
+    pub new_admin: Key,
+    pub timestamp: u64,
+}
+
+#[derive(Event)]
+pub struct Paused {
+    pub features: u8,
+    pub paused_features: u8,
+    pub sender: Key,
+    pub timestamp: u64,
+}
+
+#[derive(Event)]
+pub struct Unpaused {
+    pub features: u8,
+    pub paused_features: u8,
+    pub sender: Key,
+    pub timestamp: u64,
+}
+
+#[derive(Event)]
+pub struct CollectionRegistered {
+    pub contract_hash: ContractHash,
+    pub token_standard: u8,
+    pub identifier_mode: u8,
+    pub verified: bool,
+    pub timestamp: u64,
+}
+
+#[derive(Event)]
+pub struct CuratedModeChanged {
+    pub curated: bool,
+    pub sender: Key,
+    pub timestamp: u64,
+}
+
+#[derive(Event)]
+pub struct TokenBlocked {
+    pub contract_hash: ContractHash,
+    pub token_id: String,
+    pub identifier_mode: u8,
+    pub sender: Key,
+    pub timestamp: u64,
+}
+
+#[derive(Event)]
+pub struct TokenUnblocked {
+    pub contract_hash: ContractHash,
+    pub token_id: String,
+    pub identifier_mode: u8,
+    pub sender: Key,
+    pub timestamp: u64,
+}
+
+#[derive(Event)]
+pub struct AccountBlocked {
+    pub account: Key,
+    pub sender: Key,
+    pub timestamp: u64,
+}
+
+#[derive(Event)]
+pub struct AccountUnblocked {
+    pub account: Key,
+    pub sender: Key,
+    pub timestamp: u64,
+}
+
+#[derive(Event)]
+pub struct ListingForceDelisted {
+    pub seller: Key,
+    pub moderator: Key,
+    pub contract_hash: ContractHash,
+    pub token_id: String,
+    pub identifier_mode: u8,
+    pub payment_token: Option<ContractHash>,
+    pub timestamp: u64,
+}
+
+#[derive(Event)]
+pub struct FundsDeposited {
+    pub account: Key,
+    pub amount: U512,
+    pub timestamp: u64,
+}
+
+#[derive(Event)]
+pub struct FundsCredited {
+    pub account: Key,
+    pub payment_token: Option<ContractHash>,
+    pub amount: U512,
+    pub timestamp: u64,
+}
+
+#[derive(Event)]
+pub struct FundsSpent {
+    pub account: Key,
+    pub payment_token: Option<ContractHash>,
+    pub amount: U512,
+    pub timestamp: u64,
+}
+
+#[derive(Event)]
+pub struct FundsWithdrawn {
+    pub account: Key,
+    pub payment_token: Option<ContractHash>,
+    pub amount: U512,
+    pub timestamp: u64,
+}
+
+fn get_schemas() -> Schemas {
+    Schemas::new()
         .with::<NewListing>()
         .with::<ListingBought>()
         .with::<ListingCancelled>()
+        .with::<BundleCreated>()
+        .with::<BundleBought>()
+        .with::<BundleCancelled>()
         .with::<Bid>()
         .with::<NewOffer>()
         .with::<OfferAccepted>()
         .with::<OfferCancelled>()
         .with::<AuctionEnded>()
         .with::<AuctionStarted>()
+        .with::<AuctionExtensionChanged>()
+        .with::<DutchAuctionStarted>()
+        .with::<DutchAuctionBought>()
+        .with::<DutchAuctionCancelled>()
         .with::<RoyaltySet>()
+        .with::<MarketplaceFeeChanged>()
+        .with::<CollectionFeeSet>()
+        .with::<RoleGranted>()
+        .with::<RoleRevoked>()
+        .with::<AdminProposed>()
+        .with::<AdminProposalCancelled>()
+        .with::<AdminTransferred>()
+        .with::<Paused>()
+        .with::<Unpaused>()
+        .with::<CollectionRegistered>()
+        .with::<CuratedModeChanged>()
+        .with::<TokenBlocked>()
+        .with::<TokenUnblocked>()
+        .with::<AccountBlocked>()
+        .with::<AccountUnblocked>()
+        .with::<ListingForceDelisted>()
+        .with::<FundsDeposited>()
+        .with::<FundsCredited>()
+        .with::<FundsSpent>()
+        .with::<FundsWithdrawn>()
         .with::<Bid>()
-        .with::<NewOffer>();
-    casper_event_standard::init(schemas);
+        .with::<NewOffer>()
+}
+
+pub fn init_events() {
+    casper_event_standard::init(get_schemas());
+}
+
+// Replaces stored schemas after an upgrade, CES init can run only once
+pub fn update_events() {
+    let schemas_uref: URef = runtime::get_key(EVENTS_SCHEMA)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    storage::write(schemas_uref, get_schemas());
+}
+
+/// Collections named by every auction start emitted so far. Auction start has kept seller and
+/// collection as its first fields since the first version.
+pub fn get_auctioned_collections() -> BTreeSet<ContractHash> {
+    let events_uref: URef = runtime::get_key(EVENTS_DICT)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    let length_uref: URef = runtime::get_key(EVENTS_LENGTH)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    let length: u32 = storage::read(length_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+
+    let mut collections = BTreeSet::new();
+    for index in 0..length {
+        let event: Bytes = storage::dictionary_get(events_uref, &index.to_string())
+            .unwrap_or_revert()
+            .unwrap_or_revert();
+
+        // Stored event starts with its name
+        let (name, fields) = String::from_bytes(&event).unwrap_or_revert();
+        if name == "event_AuctionStarted" {
+            let (_seller, fields) = Key::from_bytes(fields).unwrap_or_revert();
+            let (contract_hash, _) = ContractHash::from_bytes(fields).unwrap_or_revert();
+            collections.insert(contract_hash);
+        }
+    }
+
+    collections
 }
 
 pub fn emit_create_listing(data: NewListing) {
@@ -126,6 +464,18 @@ pub fn emit_cancel_listing(data: ListingCancelled) {
     casper_event_standard::emit(data);
 }
 
+pub fn emit_bundle_created(data: BundleCreated) {
+    casper_event_standard::emit(data);
+}
+
+pub fn emit_bundle_bought(data: BundleBought) {
+    casper_event_standard::emit(data);
+}
+
+pub fn emit_bundle_cancelled(data: BundleCancelled) {
+    casper_event_standard::emit(data);
+}
+
 pub fn emit_make_offer(data: NewOffer) {
     casper_event_standard::emit(data);
 }
@@ -152,7 +502,104 @@ pub fn emit_bid(data: Bid) {
     casper_event_standard::emit(data);
 }
 
+pub fn emit_auction_extension_changed(data: AuctionExtensionChanged) {
+    casper_event_standard::emit(data);
+}
+
+pub fn emit_dutch_auction_started(data: DutchAuctionStarted) {
+    casper_event_standard::emit(data);
+}
+
+pub fn emit_dutch_auction_bought(data: DutchAuctionBought) {
+    casper_event_standard::emit(data);
+}
+
+pub fn emit_dutch_auction_cancelled(data: DutchAuctionCancelled) {
+    casper_event_standard::emit(data);
+}
+
 
 pub fn emit_royalty_set(data: RoyaltySet) {
     casper_event_standard::emit(data);
 }
+
+
+pub fn emit_marketplace_fee_changed(data: MarketplaceFeeChanged) {
+    casper_event_standard::emit(data);
+}
+
+pub fn emit_collection_fee_set(data: CollectionFeeSet) {
+    casper_event_standard::emit(data);
+}
+
+pub fn emit_role_granted(data: RoleGranted) {
+    casper_event_standard::emit(data);
+}
+
+pub fn emit_role_revoked(data: RoleRevoked) {
+    casper_event_standard::emit(data);
+}
+
+pub fn emit_admin_proposed(data: AdminProposed) {
+    casper_event_standard::emit(data);
+}
+
+pub fn emit_admin_proposal_cancelled(data: AdminProposalCancelled) {
+    casper_event_standard::emit(data);
+}
+
+pub fn emit_admin_transferred(data: AdminTransferred) {
+    casper_event_standard::emit(data);
+}
+
+pub fn emit_paused(data: Paused) {
+    casper_event_standard::emit(data);
+}
+
+pub fn emit_unpaused(data: Unpaused) {
+    casper_event_standard::emit(data);
+}
+
+pub fn emit_collection_registered(data: CollectionRegistered) {
+    casper_event_standard::emit(data);
+}
+
+pub fn emit_curated_mode_changed(data: CuratedModeChanged) {
+    casper_event_standard::emit(data);
+}
+
+pub fn emit_token_blocked(data: TokenBlocked) {
+    casper_event_standard::emit(data);
+}
+
+pub fn emit_token_unblocked(data: TokenUnblocked) {
+    casper_event_standard::emit(data);
+}
+
+pub fn emit_account_blocked(data: AccountBlocked) {
+    casper_event_standard::emit(data);
+}
+
+pub fn emit_account_unblocked(data: AccountUnblocked) {
+    casper_event_standard::emit(data);
+}
+
+pub fn emit_listing_force_delisted(data: ListingForceDelisted) {
+    casper_event_standard::emit(data);
+}
+
+pub fn emit_funds_deposited(data: FundsDeposited) {
+    casper_event_standard::emit(data);
+}
+
+pub fn emit_funds_credited(data: FundsCredited) {
+    casper_event_standard::emit(data);
+}
+
+pub fn emit_funds_spent(data: FundsSpent) {
+    casper_event_standard::emit(data);
+}
+
+pub fn emit_funds_withdrawn(data: FundsWithdrawn) {
+    casper_event_standard::emit(data);
+}
@@ -5,40 +5,104 @@ compile_error!("target arch should be wasm32: compile with '--target wasm32-unkn
 
 extern crate alloc;
 
-use alloc::string::{String, ToString};
+use alloc::{
+    collections::BTreeSet,
+    string::{String, ToString},
+    vec::Vec,
+};
 
 use casper_contract::{
     contract_api::{
         runtime::{self, revert},
-        storage, system,
+        storage,
     },
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
-    account::AccountHash, contracts::NamedKeys, runtime_args, CLValue, ContractHash, Key, RuntimeArgs, URef, U256, U512
+    account::AccountHash, contracts::NamedKeys, runtime_args, system::CallStackElement, CLValue,
+    ContractHash, ContractPackageHash, Key, RuntimeArgs, URef, U256, U512,
+};
+use adapters::{
+    detect_token_standard, escrow_token, get_cep78_balance, get_escrowed_tokens_uref,
+    get_token_adapter, release_token, transfer_escrowed_tokens, TokenIdentifier,
+};
+use blocklist::{
+    is_account_blocked, is_token_blocked, require_account_not_blocked, require_token_not_blocked,
+    set_account_blocked, set_token_blocked,
+};
+use bundles::{
+    clear_bundle_data, get_bundle_counter_uref, get_bundle_data, get_bundle_token_ids,
+    next_bundle_id, set_bundle_data,
+};
+use collections::{
+    detect_batch_collections, get_curated_mode_uref, is_collection_recorded, is_tradable,
+    require_tradable, set_collection_data, IdentifierMode, TokenStandard,
 };
 use constants::{
-    ARG_BUY_PURSE, ARG_CREATOR, ARG_DURATION_MINUTES, ARG_OFFERER, ARG_PRICE, ARG_ROYALTIES_PERCENTAGE, ARG_TOKEN_CONTRACT, ARG_TOKEN_ID, CONTRACT_ACCESS_UREF, CONTRACT_KEY, CONTRACT_PACKAGE_NAME, CONTRACT_VERSION_KEY, KEY_INSTALLER, PURSE_AUCTIONS, PURSE_OFFERS, PURSE_REUSABLE
+    ARG_ACCOUNT, ARG_AMOUNT, ARG_BUNDLE_ID, ARG_BUY_NOW_PRICE, ARG_BUY_PURSE, ARG_CREATOR,
+    ARG_CURATED, ARG_DURATIONS, ARG_DURATION_MINUTES, ARG_EXTENSION_MINUTES,
+    ARG_EXTENSION_WINDOW_MINUTES, ARG_FEATURES, ARG_FEE_BASIS_POINTS, ARG_FEE_RECIPIENT,
+    ARG_FLOOR_PRICE, ARG_IDENTIFIER_MODE, ARG_MAX_EXTENSIONS, ARG_MIN_INCREMENT,
+    ARG_MIN_INCREMENT_BASIS_POINTS, ARG_NEW_ADMIN, ARG_OFFERER, ARG_PREVIOUS_CONTRACT_HASH,
+    ARG_PRICE, ARG_PRICES, ARG_RESERVE_COMMITMENT, ARG_ROLE, ARG_ROYALTIES_PERCENTAGE,
+    ARG_SKIP_UNAVAILABLE, ARG_STEP_MINUTES, ARG_TOKEN_CONTRACT, ARG_TOKEN_CONTRACTS,
+    ARG_TOKEN_STANDARD, ARG_VERIFIED, CONTRACT_ACCESS_UREF, CONTRACT_KEY, CONTRACT_PACKAGE_NAME,
+    CONTRACT_VERSION_KEY, FEATURE_AUCTIONS, FEATURE_LISTINGS, FEATURE_OFFERS,
+    KEY_ESCROWED_TOKENS, KEY_INSTALLER, MAX_BASIS_POINTS, PURSE_AUCTIONS, PURSE_ESCROW,
+    PURSE_OFFERS,
+};
+use dutch_auctions::{
+    clear_dutch_auction_data, get_dutch_auction_data, get_dutch_auction_price,
+    set_dutch_auction_data,
 };
 use entry_points::get_entry_points;
 use events::{
-    emit_accept_offer, emit_auction_ended, emit_auction_started, emit_bid, emit_buy_listing,
-    emit_cancel_listing, emit_cancel_offer, emit_create_listing, emit_make_offer, emit_royalty_set,
-    init_events, AuctionEnded, AuctionStarted, Bid, ListingBought, ListingCancelled, NewListing,
-    NewOffer, OfferAccepted, OfferCancelled, RoyaltySet,
+    emit_accept_offer, emit_account_blocked, emit_account_unblocked, emit_admin_proposal_cancelled,
+    emit_admin_proposed, emit_admin_transferred, emit_auction_ended, emit_auction_extension_changed,
+    emit_auction_started, emit_bid, emit_bundle_bought, emit_bundle_cancelled, emit_bundle_created,
+    emit_buy_listing, emit_cancel_listing, emit_cancel_offer, emit_collection_fee_set,
+    emit_collection_registered, emit_create_listing, emit_curated_mode_changed,
+    emit_dutch_auction_bought, emit_dutch_auction_cancelled, emit_dutch_auction_started,
+    emit_listing_force_delisted, emit_make_offer, emit_marketplace_fee_changed, emit_paused,
+    emit_role_granted, emit_role_revoked, emit_royalty_set, emit_token_blocked,
+    emit_token_unblocked, emit_unpaused, get_auctioned_collections, init_events, update_events,
+    AccountBlocked, AccountUnblocked, AdminProposalCancelled, AdminProposed, AdminTransferred,
+    AuctionEnded, AuctionExtensionChanged, AuctionStarted, Bid, BundleBought, BundleCancelled,
+    BundleCreated, CollectionFeeSet, CollectionRegistered, CuratedModeChanged, DutchAuctionBought,
+    DutchAuctionCancelled, DutchAuctionStarted, ListingBought, ListingCancelled,
+    ListingForceDelisted, MarketplaceFeeChanged, NewListing, NewOffer, OfferAccepted,
+    OfferCancelled, Paused, RoleGranted, RoleRevoked, RoyaltySet, TokenBlocked, TokenUnblocked,
+    Unpaused,
+};
+use roles::{has_role, require_role, set_role, Role};
+use structs::{
+    AuctionData, AuctionExtension, BundleData, CollectionData, DutchAuctionData, ListingData,
+    OfferData, RoyaltyData,
 };
-use structs::{AuctionData, ListingData, OfferData, RoyaltyData};
 use utils::{
-    get_auction_data, get_auction_dictionary, get_installer, get_installer_uref, get_listing_data,
-    get_listing_dictionary, get_listing_key, get_offer_data, get_offer_dictionary, get_offer_key,
-    get_purse, get_royalties_dictionary, get_token_owner, get_transfer_marketplace_address,
-    minutes_to_milis, process_payment, transfer_approved, transfer_token,
+    contract_package_hash, deposit_to_escrow, extend_auction, get_auction_data,
+    get_auction_dictionary, get_auction_extension, get_auction_extension_uref,
+    get_collection_fees_dictionary, get_escrow_balance, get_fee_recipient, get_fee_recipient_uref,
+    get_installer, get_installer_uref, get_listing_data, get_listing_dictionary, get_listing_key,
+    get_marketplace_fee_uref, get_min_bid, get_offer_data, get_offer_dictionary, get_offer_key,
+    get_optional_named_arg, get_paused_features, get_paused_features_uref, get_payment_token,
+    get_pending_admin, get_pending_admin_uref, get_purse, get_royalties_dictionary,
+    get_royalty_data, get_start_time, is_reserve_met, minutes_to_milis, process_payment,
+    require_not_paused, require_started, require_valid_extension, require_valid_fee_split,
+    require_valid_reserve_commitment, resolve_marketplace_fee, spend_from_escrow,
+    withdraw_from_escrow, Funds,
 };
 
+mod adapters;
+mod blocklist;
+mod bundles;
+mod collections;
 mod constants;
+mod dutch_auctions;
 mod entry_points;
 mod error;
 mod events;
+mod roles;
 mod structs;
 mod utils;
 use error::Error;
@@ -48,13 +112,86 @@ use error::Error;
 pub extern "C" fn create_listing() -> () {
     // Read args
     let caller = Key::Account(runtime::get_caller());
-    let token_id: U256 = runtime::get_named_arg(ARG_TOKEN_ID);
+    let token_id = TokenIdentifier::from_args();
     let price: U512 = runtime::get_named_arg(ARG_PRICE);
     let duration_in_minutes: u64 = runtime::get_named_arg(ARG_DURATION_MINUTES);
-    let current_time: u64 = runtime::get_blocktime().into();
     let token_contract_string: String = runtime::get_named_arg(ARG_TOKEN_CONTRACT);
     let token_contract_hash: ContractHash =
         ContractHash::from_formatted_str(&token_contract_string).unwrap();
+    let payment_token: Option<ContractHash> = get_payment_token();
+
+    add_listing(
+        caller,
+        token_contract_hash,
+        &token_id,
+        price,
+        duration_in_minutes,
+        payment_token,
+    );
+}
+
+#[no_mangle]
+pub extern "C" fn create_listings_batch() {
+    // Read args
+    let caller = Key::Account(runtime::get_caller());
+    let token_contract_strings: Vec<String> = runtime::get_named_arg(ARG_TOKEN_CONTRACTS);
+    let token_ids: Vec<TokenIdentifier> = TokenIdentifier::batch_from_args();
+    let prices: Vec<U512> = runtime::get_named_arg(ARG_PRICES);
+    let durations_in_minutes: Vec<u64> = runtime::get_named_arg(ARG_DURATIONS);
+    let payment_token: Option<ContractHash> = get_payment_token();
+
+    // Every item needs contract, token id, price and duration
+    let items = token_contract_strings.len();
+    if token_ids.len() != items || prices.len() != items || durations_in_minutes.len() != items
+    {
+        runtime::revert(Error::BatchLengthMismatch);
+    }
+
+    // Collections seen for the first time are recorded with their item's standard
+    let contract_hashes = parse_contract_hashes(&token_contract_strings);
+    detect_batch_collections(&contract_hashes, &token_ids);
+
+    // Whole batch reverts if any item fails
+    for index in 0..items {
+        add_listing(
+            caller,
+            contract_hashes[index],
+            &token_ids[index],
+            prices[index],
+            durations_in_minutes[index],
+            payment_token,
+        );
+    }
+}
+
+fn parse_contract_hashes(token_contract_strings: &[String]) -> Vec<ContractHash> {
+    token_contract_strings
+        .iter()
+        .map(|token_contract_string| {
+            ContractHash::from_formatted_str(token_contract_string).unwrap()
+        })
+        .collect()
+}
+
+fn add_listing(
+    caller: Key,
+    token_contract_hash: ContractHash,
+    token_id: &TokenIdentifier,
+    price: U512,
+    duration_in_minutes: u64,
+    payment_token: Option<ContractHash>,
+) {
+    let current_time: u64 = runtime::get_blocktime().into();
+
+    // Listings must not be paused
+    require_not_paused(FEATURE_LISTINGS);
+
+    // Collection must be verified in curated mode
+    require_tradable(token_contract_hash);
+
+    // Blocked tokens and accounts can't be listed
+    require_token_not_blocked(token_contract_hash, token_id);
+    require_account_not_blocked(caller);
 
     // Price must be greater than 0
     if price == U512::zero() {
@@ -62,19 +199,23 @@ pub extern "C" fn create_listing() -> () {
     }
 
     // Must be current owner of token
-    let owner = get_token_owner(token_contract_hash, token_id);
+    let token = get_token_adapter(token_contract_hash);
+    let owner = token.owner_of(token_id);
     if owner != caller {
         runtime::revert(Error::PermissionDenied)
     }
 
     // Must approve token spending
-    if transfer_approved(token_contract_hash, token_id, caller) == false {
+    if token.is_approved(caller, token_id) == false {
         runtime::revert(Error::NeedsTransferApproval);
     }
 
+    // Listing can be scheduled, duration counts from its start
+    let start_time = get_start_time(current_time);
+
     // Set expiration time if its greater than 0
     let expiration_time: Option<u64> = if duration_in_minutes > 0 {
-        Some(current_time + minutes_to_milis(duration_in_minutes))
+        Some(start_time + minutes_to_milis(duration_in_minutes))
     } else {
         None
     };
@@ -84,6 +225,8 @@ pub extern "C" fn create_listing() -> () {
         price: price,
         seller: owner,
         expiration_time: expiration_time,
+        payment_token,
+        start_time,
     };
     let key = get_listing_key(token_contract_hash, token_id);
     storage::dictionary_put(get_listing_dictionary(), &key, listing_data);
@@ -93,9 +236,12 @@ pub extern "C" fn create_listing() -> () {
         seller: caller,
         contract_hash: token_contract_hash,
         token_id: token_id.to_string(),
+        identifier_mode: token_id.mode() as u8,
+        payment_token,
         price: price,
         timestamp: current_time,
-        expiration_date: current_time + minutes_to_milis(duration_in_minutes),
+        start_time,
+        expiration_date: start_time + minutes_to_milis(duration_in_minutes),
     });
 }
 
@@ -104,26 +250,59 @@ pub extern "C" fn cancel_listing() -> () {
     // Read args
     let caller = Key::Account(runtime::get_caller());
     let token_contract_string: String = runtime::get_named_arg(ARG_TOKEN_CONTRACT);
-    let token_id: U256 = runtime::get_named_arg(ARG_TOKEN_ID);
+    let token_id = TokenIdentifier::from_args();
     let token_contract_hash: ContractHash =
         ContractHash::from_formatted_str(&token_contract_string).unwrap();
 
+    remove_listing(caller, token_contract_hash, &token_id);
+}
+
+#[no_mangle]
+pub extern "C" fn cancel_listings_batch() {
+    // Read args
+    let caller = Key::Account(runtime::get_caller());
+    let token_contract_strings: Vec<String> = runtime::get_named_arg(ARG_TOKEN_CONTRACTS);
+    let token_ids: Vec<TokenIdentifier> = TokenIdentifier::batch_from_args();
+
+    // Every item needs contract and token id
+    if token_ids.len() != token_contract_strings.len() {
+        runtime::revert(Error::BatchLengthMismatch);
+    }
+
+    let contract_hashes = parse_contract_hashes(&token_contract_strings);
+    detect_batch_collections(&contract_hashes, &token_ids);
+
+    for (contract_hash, token_id) in contract_hashes.iter().zip(token_ids.iter()) {
+        remove_listing(caller, *contract_hash, token_id);
+    }
+}
+
+fn remove_listing(caller: Key, token_contract_hash: ContractHash, token_id: &TokenIdentifier) {
     // Must be token owner to cancel listing
-    let owner: Key = get_token_owner(token_contract_hash, token_id);
+    let owner: Key = get_token_adapter(token_contract_hash).owner_of(token_id);
     if owner != caller {
         runtime::revert(Error::PermissionDenied)
     }
 
+    // Read listing currency, listing may not exist
+    let key = get_listing_key(token_contract_hash, token_id);
+    let payment_token = match storage::dictionary_get::<ListingData>(get_listing_dictionary(), &key)
+    {
+        Ok(Some(listing_data)) => listing_data.payment_token,
+        _ => None,
+    };
+
     // Emit event
     emit_cancel_listing(ListingCancelled {
         seller: caller,
         contract_hash: token_contract_hash,
         token_id: token_id.to_string(),
+        identifier_mode: token_id.mode() as u8,
+        payment_token,
         timestamp: runtime::get_blocktime().into(),
     });
 
     // Clear listing
-    let key = get_listing_key(token_contract_hash, token_id);
     storage::dictionary_put(get_listing_dictionary(), &key, None::<ListingData>)
 }
 
@@ -134,17 +313,27 @@ pub extern "C" fn buy_listing() -> () {
     let token_contract_string: String = runtime::get_named_arg(ARG_TOKEN_CONTRACT);
     let token_contract_hash: ContractHash =
         ContractHash::from_formatted_str(&token_contract_string).unwrap();
-    let token_id: U256 = runtime::get_named_arg(ARG_TOKEN_ID);
-    let buyer_purse: URef = runtime::get_named_arg(ARG_BUY_PURSE);
-    let purse_balance: U512 = system::get_purse_balance(buyer_purse).unwrap();
+    let token_id = TokenIdentifier::from_args();
+    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT);
+
+    // Listings must not be paused
+    require_not_paused(FEATURE_LISTINGS);
 
     // Read listing data
-    let key = get_listing_key(token_contract_hash, token_id);
+    let key = get_listing_key(token_contract_hash, &token_id);
     let listing_data: ListingData = get_listing_data(&key);
 
-    // Checking purse balance and revert if lower than price
-    if purse_balance < listing_data.price {
-        runtime::revert(Error::BalanceInsufficient);
+    // Blocked tokens and accounts can't be bought or sold
+    require_token_not_blocked(token_contract_hash, &token_id);
+    require_account_not_blocked(buyer);
+    require_account_not_blocked(listing_data.seller);
+
+    // Collection may have lost verification while the token was listed
+    require_tradable(token_contract_hash);
+
+    // Amount paid must match listing price
+    if amount != listing_data.price {
+        runtime::revert(Error::PaymentAmountMismatch);
     }
 
     // Check if expiration time exists and revert if time passed
@@ -159,14 +348,146 @@ pub extern "C" fn buy_listing() -> () {
         None => {}
     }
 
+    // Scheduled listing can't be bought before its start
+    require_started(listing_data.start_time);
+
+    // Take exact amount from buyer in listing currency, native remainder is refunded
+    let funds = Funds::new(listing_data.payment_token, get_purse(PURSE_ESCROW));
+    funds.collect(amount);
+
+    complete_sale(buyer, token_contract_string, &token_id, &listing_data, &funds);
+}
+
+#[no_mangle]
+pub extern "C" fn buy_listings_batch() {
+    // Read args
+    let buyer = runtime::get_caller();
+    let token_contract_strings: Vec<String> = runtime::get_named_arg(ARG_TOKEN_CONTRACTS);
+    let token_ids: Vec<TokenIdentifier> = TokenIdentifier::batch_from_args();
+    let buyer_purse: URef = runtime::get_named_arg(ARG_BUY_PURSE);
+    let skip_unavailable: bool = runtime::get_named_arg(ARG_SKIP_UNAVAILABLE);
+
+    // Listings must not be paused
+    require_not_paused(FEATURE_LISTINGS);
+
+    // Every item needs contract and token id
+    if token_ids.len() != token_contract_strings.len() {
+        runtime::revert(Error::BatchLengthMismatch);
+    }
+
+    // Whole purse is credited to buyer, listings are paid from escrow
+    let deposited = deposit_to_escrow(buyer, buyer_purse);
+    let funds = Funds::Purse(get_purse(PURSE_ESCROW));
+    let mut spent = U512::zero();
+
+    for index in 0..token_ids.len() {
+        let token_contract_hash: ContractHash =
+            ContractHash::from_formatted_str(&token_contract_strings[index]).unwrap();
+        let token_id = &token_ids[index];
+
+        let listing_data = match get_available_listing(buyer, token_contract_hash, token_id) {
+            Ok(listing_data) => listing_data,
+            Err(_error) if skip_unavailable => continue,
+            Err(error) => runtime::revert(error),
+        };
+
+        // Sweep can't spend more than supplied in this deploy
+        if spent + listing_data.price > deposited {
+            runtime::revert(Error::BalanceInsufficient);
+        }
+        spend_from_escrow(buyer, None, listing_data.price);
+        spent += listing_data.price;
+
+        complete_sale(
+            Key::Account(buyer),
+            token_contract_strings[index].clone(),
+            token_id,
+            &listing_data,
+            &funds,
+        );
+    }
+
+    // Refund what wasn't spent
+    withdraw_from_escrow(buyer, None, deposited - spent);
+}
+
+/// Listing that can be bought in native tokens right now, checked upfront so sweep can skip it
+fn get_available_listing(
+    buyer: AccountHash,
+    token_contract_hash: ContractHash,
+    token_id: &TokenIdentifier,
+) -> Result<ListingData, Error> {
+    let key = get_listing_key(token_contract_hash, token_id);
+    let listing_data = match storage::dictionary_get::<ListingData>(get_listing_dictionary(), &key)
+    {
+        Ok(Some(listing_data)) => listing_data,
+        Ok(None) => return Err(Error::ListingDoesntExist),
+        Err(_error) => return Err(Error::ListingCancelledOrFinished),
+    };
+
+    // Sweep is funded with a purse, token priced listings can't be paid from it
+    if listing_data.payment_token.is_some() {
+        return Err(Error::PaymentAmountMismatch);
+    }
+
+    if let Some(expiration_time) = listing_data.expiration_time {
+        if u64::from(runtime::get_blocktime()) > expiration_time {
+            return Err(Error::ListingExpired);
+        }
+    }
+
+    if u64::from(runtime::get_blocktime()) < listing_data.start_time {
+        return Err(Error::NotStarted);
+    }
+
+    if is_token_blocked(token_contract_hash, token_id) {
+        return Err(Error::TokenBlocked);
+    }
+    if is_account_blocked(Key::Account(buyer)) || is_account_blocked(listing_data.seller) {
+        return Err(Error::AccountBlocked);
+    }
+    if !is_tradable(token_contract_hash) {
+        return Err(Error::CollectionNotVerified);
+    }
+
+    // Sweep never detects collections, a token contract that can't be detected would revert the
+    // whole sweep instead of skipping the item. Listings stored before collections were recorded
+    // are bought one by one or registered by admin.
+    if !is_collection_recorded(token_contract_hash) {
+        return Err(Error::UnknownTokenStandard);
+    }
+
+    // Seller may have moved the token or revoked approval since listing
+    let token = get_token_adapter(token_contract_hash);
+    if token.owner_of(token_id) != listing_data.seller {
+        return Err(Error::PermissionDenied);
+    }
+    if !token.is_approved(listing_data.seller, token_id) {
+        return Err(Error::NeedsTransferApproval);
+    }
+
+    Ok(listing_data)
+}
+
+/// Pays seller from already collected funds, hands token over to buyer and closes listing
+fn complete_sale(
+    buyer: Key,
+    token_contract_string: String,
+    token_id: &TokenIdentifier,
+    listing_data: &ListingData,
+    funds: &Funds,
+) {
+    let token_contract_hash: ContractHash =
+        ContractHash::from_formatted_str(&token_contract_string).unwrap();
+
     // Transfer token and money between users
     process_payment(
         listing_data.price,
-        buyer_purse,
-        token_contract_string,
+        funds,
+        &[token_contract_string],
         listing_data.seller,
     );
-    transfer_token(token_contract_hash, listing_data.seller, buyer, token_id);
+    get_token_adapter(token_contract_hash).transfer(listing_data.seller, buyer, token_id);
 
     // Emit event
     emit_buy_listing(ListingBought {
@@ -174,55 +495,259 @@ pub extern "C" fn buy_listing() -> () {
         buyer: buyer,
         contract_hash: token_contract_hash,
         token_id: token_id.to_string(),
+        identifier_mode: token_id.mode() as u8,
+        payment_token: listing_data.payment_token,
         timestamp: runtime::get_blocktime().into(),
         price: listing_data.price,
     });
 
     // Clear listing data
+    let key = get_listing_key(token_contract_hash, token_id);
     storage::dictionary_put(get_listing_dictionary(), &key, None::<ListingData>);
 }
 
+#[no_mangle]
+pub extern "C" fn create_bundle() {
+    // Read args
+    let caller = Key::Account(runtime::get_caller());
+    let token_contract_strings: Vec<String> = runtime::get_named_arg(ARG_TOKEN_CONTRACTS);
+    let token_ids: Vec<TokenIdentifier> = TokenIdentifier::batch_from_args();
+    let price: U512 = runtime::get_named_arg(ARG_PRICE);
+    let duration_in_minutes: u64 = runtime::get_named_arg(ARG_DURATION_MINUTES);
+    let payment_token: Option<ContractHash> = get_payment_token();
+    let current_time: u64 = runtime::get_blocktime().into();
+
+    // Bundles are sold like listings and share their pause flag
+    require_not_paused(FEATURE_LISTINGS);
+    require_account_not_blocked(caller);
+
+    // Bundle needs at least one token and every token needs its contract
+    if token_ids.is_empty() {
+        runtime::revert(Error::EmptyBundle);
+    }
+    if token_ids.len() != token_contract_strings.len() {
+        runtime::revert(Error::BatchLengthMismatch);
+    }
+
+    // Price must be greater than 0
+    if price == U512::zero() {
+        runtime::revert(Error::PriceSetToZero)
+    }
+
+    // Bundle may mix standards, collections seen for the first time use their item's standard
+    let contract_hashes = parse_contract_hashes(&token_contract_strings);
+    detect_batch_collections(&contract_hashes, &token_ids);
+
+    // Every token must be tradable, owned by caller and approved
+    let mut bundled_keys: BTreeSet<String> = BTreeSet::new();
+    for (contract_hash, token_id) in contract_hashes.iter().zip(token_ids.iter()) {
+        // Same token can't be bundled twice
+        if !bundled_keys.insert(get_listing_key(*contract_hash, token_id)) {
+            runtime::revert(Error::DuplicateBundleItem);
+        }
+
+        require_tradable(*contract_hash);
+        require_token_not_blocked(*contract_hash, token_id);
+        require_transferable(caller, *contract_hash, token_id);
+    }
+
+    // Set expiration time if its greater than 0
+    let expiration_time: Option<u64> = if duration_in_minutes > 0 {
+        Some(current_time + minutes_to_milis(duration_in_minutes))
+    } else {
+        None
+    };
+
+    // Tokens are kept in the form batches pass them
+    let (token_ids, token_hashes): (Vec<U256>, Vec<Option<String>>) =
+        token_ids.iter().map(TokenIdentifier::to_parts).unzip();
+
+    // Emit event
+    let bundle_id = next_bundle_id();
+    emit_bundle_created(BundleCreated {
+        bundle_id,
+        seller: caller,
+        contract_hashes: contract_hashes.clone(),
+        token_ids: token_ids.clone(),
+        token_hashes: token_hashes.clone(),
+        payment_token,
+        price,
+        timestamp: current_time,
+        expiration_date: current_time + minutes_to_milis(duration_in_minutes),
+    });
+
+    // Save bundle data
+    set_bundle_data(
+        bundle_id,
+        BundleData {
+            seller: caller,
+            contract_hashes,
+            token_ids,
+            price,
+            expiration_time,
+            payment_token,
+            token_hashes,
+        },
+    );
+}
+
+#[no_mangle]
+pub extern "C" fn cancel_bundle() {
+    // Read args
+    let caller = Key::Account(runtime::get_caller());
+    let bundle_id: u64 = runtime::get_named_arg(ARG_BUNDLE_ID);
+
+    // Only seller can cancel bundle
+    let bundle = get_bundle_data(bundle_id);
+    if bundle.seller != caller {
+        runtime::revert(Error::PermissionDenied)
+    }
+
+    // Emit event
+    emit_bundle_cancelled(BundleCancelled {
+        bundle_id,
+        seller: caller,
+        timestamp: runtime::get_blocktime().into(),
+    });
+
+    // Clear bundle
+    clear_bundle_data(bundle_id);
+}
+
+#[no_mangle]
+pub extern "C" fn buy_bundle() {
+    // Read args
+    let buyer = Key::Account(runtime::get_caller());
+    let bundle_id: u64 = runtime::get_named_arg(ARG_BUNDLE_ID);
+    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT);
+
+    // Listings must not be paused
+    require_not_paused(FEATURE_LISTINGS);
+
+    // Read bundle data
+    let bundle = get_bundle_data(bundle_id);
+
+    // Amount paid must match bundle price
+    if amount != bundle.price {
+        runtime::revert(Error::PaymentAmountMismatch);
+    }
+
+    // Revert if bundle expired
+    if let Some(expiration_time) = bundle.expiration_time {
+        if u64::from(runtime::get_blocktime()) > expiration_time {
+            runtime::revert(Error::ListingExpired)
+        }
+    }
+
+    // Blocked accounts can't trade
+    require_account_not_blocked(buyer);
+    require_account_not_blocked(bundle.seller);
+
+    // Seller must still hold every token and keep it approved
+    let token_ids: Vec<TokenIdentifier> = get_bundle_token_ids(&bundle);
+    for (contract_hash, token_id) in bundle.contract_hashes.iter().zip(token_ids.iter()) {
+        require_token_not_blocked(*contract_hash, token_id);
+        require_tradable(*contract_hash);
+        require_transferable(bundle.seller, *contract_hash, token_id);
+    }
+
+    // Take exact amount from buyer in bundle currency, native remainder is refunded
+    let funds = Funds::new(bundle.payment_token, get_purse(PURSE_ESCROW));
+    funds.collect(amount);
+
+    // Royalty and fee are taken per collection of each token
+    let token_contract_strings: Vec<String> = bundle
+        .contract_hashes
+        .iter()
+        .map(|contract_hash| contract_hash.to_formatted_string())
+        .collect();
+    process_payment(bundle.price, &funds, &token_contract_strings, bundle.seller);
+
+    // Transfer tokens
+    for (contract_hash, token_id) in bundle.contract_hashes.iter().zip(token_ids.iter()) {
+        get_token_adapter(*contract_hash).transfer(bundle.seller, buyer, token_id);
+    }
+
+    // Emit event
+    emit_bundle_bought(BundleBought {
+        bundle_id,
+        seller: bundle.seller,
+        buyer,
+        payment_token: bundle.payment_token,
+        price: bundle.price,
+        timestamp: runtime::get_blocktime().into(),
+    });
+
+    // Clear bundle
+    clear_bundle_data(bundle_id);
+}
+
+// Seller must own the token and let marketplace move it
+fn require_transferable(
+    seller: Key,
+    token_contract_hash: ContractHash,
+    token_id: &TokenIdentifier,
+) {
+    let token = get_token_adapter(token_contract_hash);
+    if token.owner_of(token_id) != seller {
+        runtime::revert(Error::PermissionDenied)
+    }
+    if !token.is_approved(seller, token_id) {
+        runtime::revert(Error::NeedsTransferApproval);
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn make_offer() -> () {
     // Read args
     let token_contract_string: String = runtime::get_named_arg(ARG_TOKEN_CONTRACT);
     let token_contract_hash: ContractHash =
         ContractHash::from_formatted_str(&token_contract_string).unwrap();
-    let token_id: U256 = runtime::get_named_arg(ARG_TOKEN_ID);
-    let offerer_purse: URef = runtime::get_named_arg(ARG_BUY_PURSE);
+    let token_id = TokenIdentifier::from_args();
+    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT);
     let duration_minutes: u64 = runtime::get_named_arg(ARG_DURATION_MINUTES);
+    let payment_token: Option<ContractHash> = get_payment_token();
+
+    // Offers must not be paused
+    require_not_paused(FEATURE_OFFERS);
+
+    // Collection must be verified in curated mode
+    require_tradable(token_contract_hash);
+
+    // Blocked tokens and accounts can't be offered on
+    require_token_not_blocked(token_contract_hash, &token_id);
+    require_account_not_blocked(Key::Account(runtime::get_caller()));
+
+    // Offer must be greater than 0
+    if amount == U512::zero() {
+        runtime::revert(Error::PriceSetToZero)
+    }
 
     // Get purses data
-    let purse_balance: U512 = system::get_purse_balance(offerer_purse).unwrap_or_revert();
     let offers_purse: URef = get_purse(PURSE_OFFERS);
 
     // If some offer already exists send money back to offerer
-    let key = get_offer_key(token_contract_hash, token_id, runtime::get_caller());
+    let key = get_offer_key(token_contract_hash, &token_id, runtime::get_caller());
     match storage::dictionary_get::<OfferData>(get_offer_dictionary(), &key) {
         Ok(d) => match d {
             Some(offer_data) => {
-                system::transfer_from_purse_to_account(
-                    offers_purse,
-                    runtime::get_caller(),
-                    offer_data.price,
-                    None,
-                )
-                .unwrap_or_revert();
+                Funds::new(offer_data.payment_token, offers_purse)
+                    .pay(Key::Account(runtime::get_caller()), offer_data.price);
             }
             None => {}
         },
         Err(_error) => {}
     }
 
-    // Transfer money from offerer to contract offer purse
-    system::transfer_from_purse_to_purse(offerer_purse, offers_purse, purse_balance, None)
-        .unwrap_or_revert();
+    // Take exact amount from offerer and hold it in offer purse or marketplace token balance
+    Funds::new(payment_token, offers_purse).collect(amount);
 
     // Create offer data
     let offer = OfferData {
-        price: purse_balance,
+        price: amount,
         expiration_time: u64::from(runtime::get_blocktime())
             + minutes_to_milis(duration_minutes),
+        payment_token,
     };
 
     // Emit event
@@ -230,7 +755,9 @@ pub extern "C" fn make_offer() -> () {
         buyer: Key::Account(runtime::get_caller()),
         contract_hash: token_contract_hash,
         token_id: token_id.to_string(),
-        price: purse_balance,
+        identifier_mode: token_id.mode() as u8,
+        payment_token,
+        price: amount,
         timestamp: runtime::get_blocktime().into(),
         expiration_date: offer.expiration_time,
     });
@@ -248,12 +775,36 @@ pub extern "C" fn accept_offer() -> () {
     let offerer_account_string: String = runtime::get_named_arg(ARG_OFFERER);
     let offerer_account_hash: AccountHash =
         AccountHash::from_formatted_str(&offerer_account_string).unwrap();
-    let token_id: U256 = runtime::get_named_arg(ARG_TOKEN_ID);
+    let token_id = TokenIdentifier::from_args();
+    let amount: Option<U512> = get_optional_named_arg(ARG_AMOUNT);
+    let payment_token: Option<ContractHash> = get_payment_token();
+
+    // Offers must not be paused
+    require_not_paused(FEATURE_OFFERS);
 
     // Load offer data
-    let key: String = get_offer_key(token_contract_hash, token_id, offerer_account_hash);
+    let key: String = get_offer_key(token_contract_hash, &token_id, offerer_account_hash);
     let offer_data = get_offer_data(&key);
 
+    // Offerer may have replaced offer since seller saw it, so seller may name what they accept
+    // Named amount is in native tokens unless payment token is named too
+    if let Some(amount) = amount {
+        if amount != offer_data.price {
+            runtime::revert(Error::PaymentAmountMismatch);
+        }
+    }
+    if (amount.is_some() || payment_token.is_some()) && payment_token != offer_data.payment_token {
+        runtime::revert(Error::PaymentTokenMismatch);
+    }
+
+    // Blocked tokens and accounts can't be bought or sold
+    require_token_not_blocked(token_contract_hash, &token_id);
+    require_account_not_blocked(Key::Account(runtime::get_caller()));
+    require_account_not_blocked(Key::Account(offerer_account_hash));
+
+    // Offer was made on a collection that may no longer be verified
+    require_tradable(token_contract_hash);
+
     // Revert if offer time passed
     if u64::from(runtime::get_blocktime()) > offer_data.expiration_time {
         runtime::revert(Error::OfferExpired)
@@ -262,15 +813,14 @@ pub extern "C" fn accept_offer() -> () {
     // Transfer money from purse to caller and transfer token
     process_payment(
         offer_data.price,
-        get_purse(PURSE_OFFERS),
-        token_contract_string,
+        &Funds::new(offer_data.payment_token, get_purse(PURSE_OFFERS)),
+        &[token_contract_string],
         Key::Account(runtime::get_caller()),
     );
-    transfer_token(
-        token_contract_hash,
+    get_token_adapter(token_contract_hash).transfer(
         Key::Account(runtime::get_caller()),
         Key::Account(offerer_account_hash),
-        token_id,
+        &token_id,
     );
 
     // Clear offer data
@@ -282,6 +832,8 @@ pub extern "C" fn accept_offer() -> () {
         seller: Key::Account(runtime::get_caller()),
         contract_hash: token_contract_hash,
         token_id: token_id.to_string(),
+        identifier_mode: token_id.mode() as u8,
+        payment_token: offer_data.payment_token,
         timestamp: runtime::get_blocktime().into(),
         price: offer_data.price,
     });
@@ -293,26 +845,23 @@ pub extern "C" fn cancel_offer() -> () {
     let token_contract_string: String = runtime::get_named_arg(ARG_TOKEN_CONTRACT);
     let token_contract_hash: ContractHash =
         ContractHash::from_formatted_str(&token_contract_string).unwrap();
-    let token_id: U256 = runtime::get_named_arg(ARG_TOKEN_ID);
+    let token_id = TokenIdentifier::from_args();
 
     // Read offer data
-    let key = get_offer_key(token_contract_hash, token_id, runtime::get_caller());
+    let key = get_offer_key(token_contract_hash, &token_id, runtime::get_caller());
     let current_offer = get_offer_data(&key);
 
     // Transfer money from offer purse back to offerer
-    system::transfer_from_purse_to_account(
-        get_purse(PURSE_OFFERS),
-        runtime::get_caller(),
-        current_offer.price,
-        None,
-    )
-    .unwrap_or_revert();
+    Funds::new(current_offer.payment_token, get_purse(PURSE_OFFERS))
+        .pay(Key::Account(runtime::get_caller()), current_offer.price);
 
     // Emit event
     emit_cancel_offer(OfferCancelled {
         buyer: Key::Account(runtime::get_caller()),
         contract_hash: token_contract_hash,
         token_id: token_id.to_string(),
+        identifier_mode: token_id.mode() as u8,
+        payment_token: current_offer.payment_token,
         timestamp: runtime::get_blocktime().into(),
     });
 
@@ -325,14 +874,63 @@ pub extern "C" fn start_auction() -> () {
     // Get runtime args
     let caller = Key::Account(runtime::get_caller());
     let token_contract_string: String = runtime::get_named_arg(ARG_TOKEN_CONTRACT);
-    let token_id: U256 = runtime::get_named_arg(ARG_TOKEN_ID);
+    let token_id = TokenIdentifier::from_args();
     let starting_price: U512 = runtime::get_named_arg(ARG_PRICE);
     let duration_in_minutes: u64 = runtime::get_named_arg(ARG_DURATION_MINUTES);
     let token_contract_hash: ContractHash =
         ContractHash::from_formatted_str(&token_contract_string).unwrap();
+    let payment_token: Option<ContractHash> = get_payment_token();
+    let reserve_commitment: Option<String> = get_optional_named_arg(ARG_RESERVE_COMMITMENT);
+    let min_increment: U512 = get_optional_named_arg(ARG_MIN_INCREMENT).unwrap_or_default();
+    let min_increment_basis_points: u64 =
+        get_optional_named_arg(ARG_MIN_INCREMENT_BASIS_POINTS).unwrap_or_default();
+    let buy_now_price: Option<U512> = get_optional_named_arg(ARG_BUY_NOW_PRICE);
+
+    // Extension settings not passed fall back to marketplace default
+    let default_extension = get_auction_extension();
+    let extension = AuctionExtension {
+        window: get_optional_named_arg(ARG_EXTENSION_WINDOW_MINUTES)
+            .map(minutes_to_milis)
+            .unwrap_or(default_extension.window),
+        length: get_optional_named_arg(ARG_EXTENSION_MINUTES)
+            .map(minutes_to_milis)
+            .unwrap_or(default_extension.length),
+        max_extensions: get_optional_named_arg(ARG_MAX_EXTENSIONS)
+            .unwrap_or(default_extension.max_extensions),
+    };
+
+    // Auctions must not be paused
+    require_not_paused(FEATURE_AUCTIONS);
+
+    // Collection must be verified in curated mode
+    require_tradable(token_contract_hash);
+
+    // Blocked tokens and accounts can't be auctioned
+    require_token_not_blocked(token_contract_hash, &token_id);
+    require_account_not_blocked(caller);
+
+    // Extension settings must be usable
+    require_valid_extension(&extension);
+
+    // Reserve is only committed to here, its price is checked when seller reveals it
+    if let Some(commitment) = &reserve_commitment {
+        require_valid_reserve_commitment(commitment);
+    }
+
+    // Relative increment can't exceed whole bid
+    if min_increment_basis_points > MAX_BASIS_POINTS {
+        runtime::revert(Error::InvalidBidIncrement);
+    }
+
+    // Buy now must beat starting price, seller sets it knowing own reserve
+    if let Some(buy_now_price) = buy_now_price {
+        if buy_now_price <= starting_price {
+            runtime::revert(Error::InvalidBuyNowPrice);
+        }
+    }
 
     // Token must not be listed
-    let key = get_listing_key(token_contract_hash, token_id);
+    let key = get_listing_key(token_contract_hash, &token_id);
     match storage::dictionary_get::<ListingData>(get_listing_dictionary(), &key) {
         Ok(d) => match d {
             Some(_offer_data) => {
@@ -343,8 +941,9 @@ pub extern "C" fn start_auction() -> () {
         Err(_error) => {}
     }
 
-    // Get current time
+    // Get current time, auction can be scheduled and lasts duration from its start
     let current_time: u64 = runtime::get_blocktime().into();
+    let start_time = get_start_time(current_time);
 
     // Create auction data
     let auction_data = AuctionData {
@@ -352,7 +951,15 @@ pub extern "C" fn start_auction() -> () {
         starting_price: starting_price,
         seller: runtime::get_caller(),
         current_winner: runtime::get_caller(),
-        end_time: current_time + minutes_to_milis(duration_in_minutes),
+        end_time: start_time + minutes_to_milis(duration_in_minutes),
+        payment_token,
+        reserve_commitment,
+        min_increment,
+        min_increment_basis_points,
+        extension,
+        extension_count: 0,
+        buy_now_price,
+        start_time,
     };
 
     // Emit event
@@ -360,17 +967,17 @@ pub extern "C" fn start_auction() -> () {
         seller: caller,
         contract_hash: token_contract_hash,
         token_id: token_id.to_string(),
+        identifier_mode: token_id.mode() as u8,
+        payment_token,
         starting_price,
+        buy_now_price,
         timestamp: current_time,
+        start_time,
         end_date: auction_data.end_time,
     });
 
-    transfer_token(
-        token_contract_hash,
-        caller,
-        get_transfer_marketplace_address(),
-        token_id,
-    );
+    let token = get_token_adapter(token_contract_hash);
+    escrow_token(token.as_ref(), caller, &token_id);
 
     // Save auction data
     storage::dictionary_put(get_auction_dictionary(), &key, auction_data)
@@ -382,52 +989,62 @@ pub extern "C" fn place_bid() -> () {
     let token_contract_string: String = runtime::get_named_arg(ARG_TOKEN_CONTRACT);
     let token_contract_hash: ContractHash =
         ContractHash::from_formatted_str(&token_contract_string).unwrap();
-    let token_id: U256 = runtime::get_named_arg(ARG_TOKEN_ID);
-    let buyer_purse: URef = runtime::get_named_arg(ARG_BUY_PURSE);
-    let purse_balance: U512 = system::get_purse_balance(buyer_purse).unwrap();
+    let token_id = TokenIdentifier::from_args();
+    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT);
+
+    // Auctions must not be paused
+    require_not_paused(FEATURE_AUCTIONS);
 
     // Read auction data
-    let key = get_listing_key(token_contract_hash, token_id);
+    let key = get_listing_key(token_contract_hash, &token_id);
     let mut auction_data: AuctionData = get_auction_data(&key);
 
+    // Blocked tokens and accounts can't be bid on
+    require_token_not_blocked(token_contract_hash, &token_id);
+    require_account_not_blocked(Key::Account(runtime::get_caller()));
+    require_account_not_blocked(Key::Account(auction_data.seller));
+
+    // Bids stop once the auctioned collection loses verification
+    require_tradable(token_contract_hash);
+
     // Bid must be higher than current bid
-    if purse_balance <= auction_data.current_bid {
+    if amount <= auction_data.current_bid {
         revert(Error::BidTooLow)
     }
 
+    // And raise it by at least minimum increment
+    if amount < get_min_bid(&auction_data) {
+        revert(Error::BidIncrementTooLow)
+    }
+
     // Read current time
     let current_time: u64 = runtime::get_blocktime().into();
 
-    // Revert if auction time passed
+    // Revert if auction hasn't started yet or time passed
+    require_started(auction_data.start_time);
     if current_time > auction_data.end_time {
         runtime::revert(Error::AuctionEnded);
     }
 
-    // If time until end is smaller than 10 minutes set end time 10 minutes from now
-    if current_time - auction_data.end_time < minutes_to_milis(10) {
-        auction_data.end_time = current_time + minutes_to_milis(10);
-    }
+    // Extend auction if bid came within extension window before end
+    extend_auction(&mut auction_data, current_time);
 
-    // Read auction purse
-    let auctions_purse: URef = get_purse(PURSE_AUCTIONS);
+    // Bids are held in auctions purse or marketplace token balance
+    let funds = Funds::new(auction_data.payment_token, get_purse(PURSE_AUCTIONS));
 
     // Send transfer to previous bidder, if current_bid == starting_price there are no bidders yet
     if auction_data.current_bid != auction_data.starting_price {
-        system::transfer_from_purse_to_account(
-            auctions_purse,
-            auction_data.current_winner,
+        funds.pay(
+            Key::Account(auction_data.current_winner),
             auction_data.current_bid,
-            None,
-        )
-        .unwrap_or_revert();
+        );
     }
 
-    // Transfer money from user to auctions purse
-    system::transfer_from_purse_to_purse(buyer_purse, auctions_purse, purse_balance, None)
-        .unwrap_or_revert();
+    // Take exact bid from bidder
+    funds.collect(amount);
 
     // Update auction data
-    auction_data.current_bid = purse_balance;
+    auction_data.current_bid = amount;
     auction_data.current_winner = runtime::get_caller();
 
     // Emit event
@@ -435,8 +1052,10 @@ pub extern "C" fn place_bid() -> () {
         seller: Key::Account(auction_data.seller),
         bidder: Key::Account(runtime::get_caller()),
         contract_hash: token_contract_hash,
-        bid_price: purse_balance,
+        bid_price: amount,
         token_id: token_id.to_string(),
+        identifier_mode: token_id.mode() as u8,
+        payment_token: auction_data.payment_token,
         timestamp: current_time,
         new_end_timestamp: auction_data.end_time,
     });
@@ -451,10 +1070,10 @@ pub extern "C" fn end_auction() -> () {
     let token_contract_string: String = runtime::get_named_arg(ARG_TOKEN_CONTRACT);
     let token_contract_hash: ContractHash =
         ContractHash::from_formatted_str(&token_contract_string).unwrap();
-    let token_id: U256 = runtime::get_named_arg(ARG_TOKEN_ID);
+    let token_id = TokenIdentifier::from_args();
 
     // Get auction data
-    let key = get_listing_key(token_contract_hash, token_id);
+    let key = get_listing_key(token_contract_hash, &token_id);
     let auction_data: AuctionData = get_auction_data(&key);
 
     // Read current time
@@ -465,64 +1084,340 @@ pub extern "C" fn end_auction() -> () {
         runtime::revert(Error::AuctionNotFinished);
     }
 
-    // If someone already bidded, transfer assets, else transfer token back to user
-    if auction_data.current_bid != auction_data.starting_price {
+    // Blocked tokens and accounts can't be settled, auction is unwound like an unsold one
+    let blocked = is_token_blocked(token_contract_hash, &token_id)
+        || is_account_blocked(Key::Account(auction_data.seller))
+        || is_account_blocked(Key::Account(auction_data.current_winner));
+
+    // If someone bidded at least revealed reserve price, transfer assets, else transfer token back
+    let has_bids = auction_data.current_bid != auction_data.starting_price;
+    let sold = has_bids && !blocked && is_reserve_met(&auction_data, current_time);
+    if sold {
+        // Settlement is blocked while auctions are paused, returning unsold token is not
+        require_not_paused(FEATURE_AUCTIONS);
+
         process_payment(
             auction_data.current_bid,
-            get_purse(PURSE_AUCTIONS),
-            token_contract_string,
-            Key::Account(auction_data.current_winner),
+            &Funds::new(auction_data.payment_token, get_purse(PURSE_AUCTIONS)),
+            &[token_contract_string],
+            Key::Account(auction_data.seller),
         );
-        transfer_token(
-            token_contract_hash,
-            get_transfer_marketplace_address(),
+        let token = get_token_adapter(token_contract_hash);
+        release_token(
+            token.as_ref(),
             Key::Account(auction_data.current_winner),
-            token_id,
+            &token_id,
         );
     } else {
-        
-        transfer_token(
-            token_contract_hash,
-            get_transfer_marketplace_address(),
-            Key::Account(auction_data.seller),
-            token_id,
-        );
+        // Highest bid below reserve price or on blocked auction is refunded
+        if has_bids {
+            Funds::new(auction_data.payment_token, get_purse(PURSE_AUCTIONS)).pay(
+                Key::Account(auction_data.current_winner),
+                auction_data.current_bid,
+            );
+        }
 
+        let token = get_token_adapter(token_contract_hash);
+        release_token(token.as_ref(), Key::Account(auction_data.seller), &token_id);
     }
 
+    // Unsold auction is won back by seller
+    let winner = if sold {
+        auction_data.current_winner
+    } else {
+        auction_data.seller
+    };
+
     emit_auction_ended(AuctionEnded {
         seller: Key::Account(auction_data.seller),
-        winner: Key::Account(auction_data.current_winner),
+        winner: Key::Account(winner),
         contract_hash: token_contract_hash,
         token_id: token_id.to_string(),
+        identifier_mode: token_id.mode() as u8,
+        payment_token: auction_data.payment_token,
         ending_price: auction_data.current_bid,
+        buy_now: false,
         timestamp: runtime::get_blocktime().into(),
     });
 
-    storage::dictionary_put(get_listing_dictionary(), &key, None::<AuctionData>)
+    storage::dictionary_put(get_auction_dictionary(), &key, None::<AuctionData>)
 }
 
 #[no_mangle]
-pub extern "C" fn set_royalties() -> () {
-    // Get runtime args
+pub extern "C" fn buy_now() {
+    // Read runtime args
+    let buyer = Key::Account(runtime::get_caller());
     let token_contract_string: String = runtime::get_named_arg(ARG_TOKEN_CONTRACT);
-    let percentage: u64 = runtime::get_named_arg(ARG_ROYALTIES_PERCENTAGE);
-    let creator_string: String = runtime::get_named_arg(ARG_CREATOR);
-    let creator_account_hash: AccountHash =
-        AccountHash::from_formatted_str(&creator_string).unwrap();
-    let creator_key: Key = creator_account_hash.into();
+    let token_contract_hash: ContractHash =
+        ContractHash::from_formatted_str(&token_contract_string).unwrap();
+    let token_id = TokenIdentifier::from_args();
+    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT);
 
-    // Only installer can set royalties
-    let installer: Key = get_installer();
-    if Key::Account(runtime::get_caller()) != installer {
-        runtime::revert(Error::CallerNotInstaller);
-    }
+    // Auctions must not be paused
+    require_not_paused(FEATURE_AUCTIONS);
 
-    // Create royalty data
-    let royalty_data = RoyaltyData {
-        percentage: percentage,
-        creator: creator_key,
-    };
+    // Read auction data
+    let key = get_listing_key(token_contract_hash, &token_id);
+    let auction_data: AuctionData = get_auction_data(&key);
+    let seller = Key::Account(auction_data.seller);
+
+    // Blocked tokens and accounts can't be bought or sold
+    require_token_not_blocked(token_contract_hash, &token_id);
+    require_account_not_blocked(buyer);
+    require_account_not_blocked(seller);
+
+    // Buy now settles the auction, so collection must still be verified
+    require_tradable(token_contract_hash);
+
+    // Buy now is open until auction ends or bids reach its price
+    let buy_now_price = match auction_data.buy_now_price {
+        Some(price) if auction_data.current_bid < price => price,
+        _ => runtime::revert(Error::BuyNowUnavailable),
+    };
+    let current_time: u64 = runtime::get_blocktime().into();
+    require_started(auction_data.start_time);
+    if current_time > auction_data.end_time {
+        runtime::revert(Error::AuctionEnded);
+    }
+
+    // Amount paid must match buy now price
+    if amount != buy_now_price {
+        runtime::revert(Error::PaymentAmountMismatch);
+    }
+
+    // Refund highest bidder, if current_bid == starting_price there are no bidders yet
+    let funds = Funds::new(auction_data.payment_token, get_purse(PURSE_AUCTIONS));
+    if auction_data.current_bid != auction_data.starting_price {
+        funds.pay(
+            Key::Account(auction_data.current_winner),
+            auction_data.current_bid,
+        );
+    }
+
+    // Take exact price from buyer
+    funds.collect(buy_now_price);
+
+    // Transfer token and money between users
+    process_payment(buy_now_price, &funds, &[token_contract_string], seller);
+    let token = get_token_adapter(token_contract_hash);
+    release_token(token.as_ref(), buyer, &token_id);
+
+    emit_auction_ended(AuctionEnded {
+        seller,
+        winner: buyer,
+        contract_hash: token_contract_hash,
+        token_id: token_id.to_string(),
+        identifier_mode: token_id.mode() as u8,
+        payment_token: auction_data.payment_token,
+        ending_price: buy_now_price,
+        buy_now: true,
+        timestamp: current_time,
+    });
+
+    storage::dictionary_put(get_auction_dictionary(), &key, None::<AuctionData>)
+}
+
+#[no_mangle]
+pub extern "C" fn start_dutch_auction() {
+    // Read args
+    let caller = Key::Account(runtime::get_caller());
+    let token_contract_string: String = runtime::get_named_arg(ARG_TOKEN_CONTRACT);
+    let token_contract_hash: ContractHash =
+        ContractHash::from_formatted_str(&token_contract_string).unwrap();
+    let token_id = TokenIdentifier::from_args();
+    let start_price: U512 = runtime::get_named_arg(ARG_PRICE);
+    let floor_price: U512 = runtime::get_named_arg(ARG_FLOOR_PRICE);
+    let duration_in_minutes: u64 = runtime::get_named_arg(ARG_DURATION_MINUTES);
+    let step_in_minutes: u64 = get_optional_named_arg(ARG_STEP_MINUTES).unwrap_or(0);
+    let payment_token: Option<ContractHash> = get_payment_token();
+
+    // Auctions must not be paused
+    require_not_paused(FEATURE_AUCTIONS);
+
+    // Collection must be verified in curated mode
+    require_tradable(token_contract_hash);
+
+    // Blocked tokens and accounts can't be auctioned
+    require_token_not_blocked(token_contract_hash, &token_id);
+    require_account_not_blocked(caller);
+
+    // Price must be greater than 0
+    if start_price == U512::zero() || floor_price == U512::zero() {
+        runtime::revert(Error::PriceSetToZero)
+    }
+
+    // Price can only fall and needs time to do so
+    if floor_price > start_price {
+        runtime::revert(Error::InvalidPriceRange);
+    }
+    if duration_in_minutes == 0 {
+        runtime::revert(Error::InvalidDuration);
+    }
+
+    // Token must not be listed
+    let key = get_listing_key(token_contract_hash, &token_id);
+    if let Ok(Some(_listing_data)) =
+        storage::dictionary_get::<ListingData>(get_listing_dictionary(), &key)
+    {
+        runtime::revert(Error::TokenAlreadyOnListing)
+    }
+
+    // Get current time, price starts falling at scheduled start
+    let current_time: u64 = runtime::get_blocktime().into();
+    let start_time = get_start_time(current_time);
+
+    // Create auction data
+    let auction_data = DutchAuctionData {
+        seller: runtime::get_caller(),
+        start_price,
+        floor_price,
+        start_time,
+        end_time: start_time + minutes_to_milis(duration_in_minutes),
+        step_interval: minutes_to_milis(step_in_minutes),
+        payment_token,
+    };
+
+    // Emit event
+    emit_dutch_auction_started(DutchAuctionStarted {
+        seller: caller,
+        contract_hash: token_contract_hash,
+        token_id: token_id.to_string(),
+        identifier_mode: token_id.mode() as u8,
+        payment_token,
+        start_price,
+        floor_price,
+        step_interval: auction_data.step_interval,
+        timestamp: current_time,
+        start_time,
+        end_date: auction_data.end_time,
+    });
+
+    // Marketplace holds token until it's bought or auction is cancelled
+    let token = get_token_adapter(token_contract_hash);
+    escrow_token(token.as_ref(), caller, &token_id);
+
+    // Save auction data
+    set_dutch_auction_data(&key, auction_data);
+}
+
+#[no_mangle]
+pub extern "C" fn buy_dutch_auction() {
+    // Read args
+    let buyer = Key::Account(runtime::get_caller());
+    let token_contract_string: String = runtime::get_named_arg(ARG_TOKEN_CONTRACT);
+    let token_contract_hash: ContractHash =
+        ContractHash::from_formatted_str(&token_contract_string).unwrap();
+    let token_id = TokenIdentifier::from_args();
+    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT);
+
+    // Auctions must not be paused
+    require_not_paused(FEATURE_AUCTIONS);
+
+    // Read auction data
+    let key = get_listing_key(token_contract_hash, &token_id);
+    let auction_data: DutchAuctionData = get_dutch_auction_data(&key);
+    let seller = Key::Account(auction_data.seller);
+
+    // Blocked tokens and accounts can't be bought or sold
+    require_token_not_blocked(token_contract_hash, &token_id);
+    require_account_not_blocked(buyer);
+    require_account_not_blocked(seller);
+
+    // Dutch auction may have started before collection lost verification
+    require_tradable(token_contract_hash);
+
+    // Scheduled auction can't be bought before its start
+    require_started(auction_data.start_time);
+
+    // Amount is the most buyer is willing to pay, price may fall before deploy executes
+    let current_time: u64 = runtime::get_blocktime().into();
+    let price = get_dutch_auction_price(&auction_data, current_time);
+    if amount < price {
+        runtime::revert(Error::BidTooLow);
+    }
+
+    // Take current price from buyer, native remainder is refunded
+    let funds = Funds::new(auction_data.payment_token, get_purse(PURSE_ESCROW));
+    funds.collect(price);
+
+    // Transfer token and money between users
+    process_payment(price, &funds, &[token_contract_string], seller);
+    let token = get_token_adapter(token_contract_hash);
+    release_token(token.as_ref(), buyer, &token_id);
+
+    // Emit event
+    emit_dutch_auction_bought(DutchAuctionBought {
+        seller,
+        buyer,
+        contract_hash: token_contract_hash,
+        token_id: token_id.to_string(),
+        identifier_mode: token_id.mode() as u8,
+        payment_token: auction_data.payment_token,
+        price,
+        timestamp: current_time,
+    });
+
+    // Clear auction data
+    clear_dutch_auction_data(&key);
+}
+
+#[no_mangle]
+pub extern "C" fn cancel_dutch_auction() {
+    // Read args
+    let caller = Key::Account(runtime::get_caller());
+    let token_contract_string: String = runtime::get_named_arg(ARG_TOKEN_CONTRACT);
+    let token_contract_hash: ContractHash =
+        ContractHash::from_formatted_str(&token_contract_string).unwrap();
+    let token_id = TokenIdentifier::from_args();
+
+    // Only seller can cancel auction. Dutch auctions never expire, so admins can cancel them too
+    // to release tokens nobody is going to buy.
+    let key = get_listing_key(token_contract_hash, &token_id);
+    let auction_data: DutchAuctionData = get_dutch_auction_data(&key);
+    let seller = Key::Account(auction_data.seller);
+    if seller != caller && !has_role(Role::Admin, caller) {
+        runtime::revert(Error::PermissionDenied)
+    }
+
+    // Return token to seller
+    let token = get_token_adapter(token_contract_hash);
+    release_token(token.as_ref(), seller, &token_id);
+
+    // Emit event
+    emit_dutch_auction_cancelled(DutchAuctionCancelled {
+        seller,
+        contract_hash: token_contract_hash,
+        token_id: token_id.to_string(),
+        identifier_mode: token_id.mode() as u8,
+        payment_token: auction_data.payment_token,
+        timestamp: runtime::get_blocktime().into(),
+    });
+
+    // Clear auction data
+    clear_dutch_auction_data(&key);
+}
+
+#[no_mangle]
+pub extern "C" fn set_royalties() -> () {
+    // Get runtime args
+    let token_contract_string: String = runtime::get_named_arg(ARG_TOKEN_CONTRACT);
+    let percentage: u64 = runtime::get_named_arg(ARG_ROYALTIES_PERCENTAGE);
+    let creator_string: String = runtime::get_named_arg(ARG_CREATOR);
+    let creator_account_hash: AccountHash =
+        AccountHash::from_formatted_str(&creator_string).unwrap();
+    let creator_key: Key = creator_account_hash.into();
+
+    // Only royalty managers can set royalties
+    require_role(Role::RoyaltyManager);
+
+    // Royalty and marketplace fee can't exceed whole price
+    require_valid_fee_split(percentage, resolve_marketplace_fee(&token_contract_string));
+
+    // Create royalty data
+    let royalty_data = RoyaltyData {
+        percentage: percentage,
+        creator: creator_key,
+    };
 
     // Emit event
     emit_royalty_set(RoyaltySet {
@@ -539,22 +1434,677 @@ pub extern "C" fn set_royalties() -> () {
     )
 }
 
+#[no_mangle]
+pub extern "C" fn set_marketplace_fee() {
+    // Get runtime args
+    let fee_basis_points: u64 = runtime::get_named_arg(ARG_FEE_BASIS_POINTS);
+
+    // Only fee managers can set marketplace fee
+    require_role(Role::FeeManager);
+
+    // Fee can't exceed whole price
+    if fee_basis_points > MAX_BASIS_POINTS {
+        runtime::revert(Error::InvalidFee);
+    }
+
+    // Save fee
+    storage::write(get_marketplace_fee_uref(), fee_basis_points);
+
+    // Emit event
+    emit_marketplace_fee_changed(MarketplaceFeeChanged {
+        fee_basis_points,
+        fee_recipient: get_fee_recipient(),
+        timestamp: runtime::get_blocktime().into(),
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn set_auction_extension() {
+    // Get runtime args
+    let window_in_minutes: u64 = runtime::get_named_arg(ARG_EXTENSION_WINDOW_MINUTES);
+    let extension_in_minutes: u64 = runtime::get_named_arg(ARG_EXTENSION_MINUTES);
+    let max_extensions: u64 = runtime::get_named_arg(ARG_MAX_EXTENSIONS);
+
+    // Only admins can change auction defaults
+    require_role(Role::Admin);
+
+    // Save default, running auctions keep their own settings
+    let extension = AuctionExtension {
+        window: minutes_to_milis(window_in_minutes),
+        length: minutes_to_milis(extension_in_minutes),
+        max_extensions,
+    };
+    require_valid_extension(&extension);
+
+    // Emit event
+    emit_auction_extension_changed(AuctionExtensionChanged {
+        window: extension.window,
+        length: extension.length,
+        max_extensions,
+        sender: Key::Account(runtime::get_caller()),
+        timestamp: runtime::get_blocktime().into(),
+    });
+
+    storage::write(get_auction_extension_uref(), extension);
+}
+
+#[no_mangle]
+pub extern "C" fn set_fee_recipient() {
+    // Get runtime args, none means fees go to installer
+    let fee_recipient: Option<Key> = runtime::get_named_arg(ARG_FEE_RECIPIENT);
+
+    // Only fee managers can set fee recipient
+    require_role(Role::FeeManager);
+
+    // Fees are credited to account balances, so recipient must be an account
+    if fee_recipient.map_or(false, |key| key.into_account().is_none()) {
+        runtime::revert(Error::InvalidFeeRecipient);
+    }
+
+    // Save recipient
+    storage::write(get_fee_recipient_uref(), fee_recipient);
+
+    // Emit event
+    emit_marketplace_fee_changed(MarketplaceFeeChanged {
+        fee_basis_points: utils::get_marketplace_fee(),
+        fee_recipient: get_fee_recipient(),
+        timestamp: runtime::get_blocktime().into(),
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn set_collection_fee() {
+    // Get runtime args, none removes collection override
+    let token_contract_string: String = runtime::get_named_arg(ARG_TOKEN_CONTRACT);
+    let fee_basis_points: Option<u64> = runtime::get_named_arg(ARG_FEE_BASIS_POINTS);
+    let token_contract_hash: ContractHash =
+        ContractHash::from_formatted_str(&token_contract_string).unwrap();
+
+    // Only fee managers can set collection fee
+    require_role(Role::FeeManager);
+
+    // Fee can't exceed whole price
+    if fee_basis_points.unwrap_or_default() > MAX_BASIS_POINTS {
+        runtime::revert(Error::InvalidFee);
+    }
+
+    // Override and collection royalty can't exceed whole price
+    if let Some(fee_basis_points) = fee_basis_points {
+        let royalty_percentage = get_royalty_data(&token_contract_string)
+            .map_or(0, |royalty| royalty.percentage);
+        require_valid_fee_split(royalty_percentage, fee_basis_points);
+    }
+
+    // Save collection fee
+    storage::dictionary_put(
+        get_collection_fees_dictionary(),
+        &token_contract_string,
+        fee_basis_points,
+    );
+
+    // Emit event
+    emit_collection_fee_set(CollectionFeeSet {
+        contract_hash: token_contract_hash,
+        fee_basis_points,
+        timestamp: runtime::get_blocktime().into(),
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn register_collection() {
+    // Get runtime args
+    let token_contract_string: String = runtime::get_named_arg(ARG_TOKEN_CONTRACT);
+    let token_contract_hash: ContractHash =
+        ContractHash::from_formatted_str(&token_contract_string).unwrap();
+    let token_standard: u8 = runtime::get_named_arg(ARG_TOKEN_STANDARD);
+    let identifier_mode: u8 = runtime::get_named_arg(ARG_IDENTIFIER_MODE);
+    // Registered collections are verified unless admin explicitly revokes it
+    let verified: bool = get_optional_named_arg(ARG_VERIFIED).unwrap_or(true);
+
+    // Only admins can register collections
+    require_role(Role::Admin);
+
+    // Save collection data, reverts on unknown values
+    let collection = CollectionData {
+        token_standard: TokenStandard::from_u8(token_standard) as u8,
+        identifier_mode: IdentifierMode::from_u8(identifier_mode) as u8,
+        verified,
+    };
+    set_collection_data(token_contract_hash, collection);
+
+    // Emit event
+    emit_collection_registered(CollectionRegistered {
+        contract_hash: token_contract_hash,
+        token_standard,
+        identifier_mode,
+        verified,
+        timestamp: runtime::get_blocktime().into(),
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn set_curated_mode() {
+    // Get runtime args
+    let curated: bool = runtime::get_named_arg(ARG_CURATED);
+
+    // Only admins can switch between open and curated mode
+    require_role(Role::Admin);
+
+    storage::write(get_curated_mode_uref(), curated);
+
+    // Emit event
+    emit_curated_mode_changed(CuratedModeChanged {
+        curated,
+        sender: Key::Account(runtime::get_caller()),
+        timestamp: runtime::get_blocktime().into(),
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn block_token() {
+    // Get runtime args
+    let token_contract_string: String = runtime::get_named_arg(ARG_TOKEN_CONTRACT);
+    let token_contract_hash: ContractHash =
+        ContractHash::from_formatted_str(&token_contract_string).unwrap();
+    let token_id = TokenIdentifier::from_args();
+
+    // Only moderators can block tokens
+    require_role(Role::Moderator);
+
+    set_token_blocked(token_contract_hash, &token_id, true);
+
+    // Emit event
+    emit_token_blocked(TokenBlocked {
+        contract_hash: token_contract_hash,
+        token_id: token_id.to_string(),
+        identifier_mode: token_id.mode() as u8,
+        sender: Key::Account(runtime::get_caller()),
+        timestamp: runtime::get_blocktime().into(),
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn unblock_token() {
+    // Get runtime args
+    let token_contract_string: String = runtime::get_named_arg(ARG_TOKEN_CONTRACT);
+    let token_contract_hash: ContractHash =
+        ContractHash::from_formatted_str(&token_contract_string).unwrap();
+    let token_id = TokenIdentifier::from_args();
+
+    // Only moderators can unblock tokens
+    require_role(Role::Moderator);
+
+    set_token_blocked(token_contract_hash, &token_id, false);
+
+    // Emit event
+    emit_token_unblocked(TokenUnblocked {
+        contract_hash: token_contract_hash,
+        token_id: token_id.to_string(),
+        identifier_mode: token_id.mode() as u8,
+        sender: Key::Account(runtime::get_caller()),
+        timestamp: runtime::get_blocktime().into(),
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn block_account() {
+    // Get runtime args
+    let account: Key = runtime::get_named_arg(ARG_ACCOUNT);
+
+    // Only moderators can block accounts
+    require_role(Role::Moderator);
+
+    set_account_blocked(account, true);
+
+    // Emit event
+    emit_account_blocked(AccountBlocked {
+        account,
+        sender: Key::Account(runtime::get_caller()),
+        timestamp: runtime::get_blocktime().into(),
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn unblock_account() {
+    // Get runtime args
+    let account: Key = runtime::get_named_arg(ARG_ACCOUNT);
+
+    // Only moderators can unblock accounts
+    require_role(Role::Moderator);
+
+    set_account_blocked(account, false);
+
+    // Emit event
+    emit_account_unblocked(AccountUnblocked {
+        account,
+        sender: Key::Account(runtime::get_caller()),
+        timestamp: runtime::get_blocktime().into(),
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn force_delist() {
+    // Get runtime args
+    let token_contract_string: String = runtime::get_named_arg(ARG_TOKEN_CONTRACT);
+    let token_contract_hash: ContractHash =
+        ContractHash::from_formatted_str(&token_contract_string).unwrap();
+    let token_id = TokenIdentifier::from_args();
+
+    // Only moderators can remove listings of other users
+    require_role(Role::Moderator);
+
+    // Only blocked tokens can be delisted
+    if !is_token_blocked(token_contract_hash, &token_id) {
+        runtime::revert(Error::TokenNotBlocked);
+    }
+
+    // Read listing data
+    let key = get_listing_key(token_contract_hash, &token_id);
+    let listing_data: ListingData = get_listing_data(&key);
+
+    // Emit event
+    emit_listing_force_delisted(ListingForceDelisted {
+        seller: listing_data.seller,
+        moderator: Key::Account(runtime::get_caller()),
+        contract_hash: token_contract_hash,
+        token_id: token_id.to_string(),
+        identifier_mode: token_id.mode() as u8,
+        payment_token: listing_data.payment_token,
+        timestamp: runtime::get_blocktime().into(),
+    });
+
+    // Clear listing
+    storage::dictionary_put(get_listing_dictionary(), &key, None::<ListingData>)
+}
+
+#[no_mangle]
+pub extern "C" fn get_collection_fee() {
+    let token_contract_string: String = runtime::get_named_arg(ARG_TOKEN_CONTRACT);
+
+    runtime::ret(
+        CLValue::from_t(resolve_marketplace_fee(&token_contract_string)).unwrap_or_revert(),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn get_marketplace_fee() {
+    runtime::ret(CLValue::from_t(utils::get_marketplace_fee()).unwrap_or_revert())
+}
+
+#[no_mangle]
+pub extern "C" fn get_listing() {
+    // Read args
+    let token_contract_string: String = runtime::get_named_arg(ARG_TOKEN_CONTRACT);
+    let token_contract_hash: ContractHash =
+        ContractHash::from_formatted_str(&token_contract_string).unwrap();
+    let token_id = TokenIdentifier::from_args();
+
+    let key = get_listing_key(token_contract_hash, &token_id);
+    runtime::ret(CLValue::from_t(get_listing_data(&key).fields()).unwrap_or_revert())
+}
+
+#[no_mangle]
+pub extern "C" fn get_bundle() {
+    let bundle_id: u64 = runtime::get_named_arg(ARG_BUNDLE_ID);
+
+    runtime::ret(CLValue::from_t(get_bundle_data(bundle_id)).unwrap_or_revert())
+}
+
+#[no_mangle]
+pub extern "C" fn get_offer() {
+    // Read args
+    let token_contract_string: String = runtime::get_named_arg(ARG_TOKEN_CONTRACT);
+    let token_contract_hash: ContractHash =
+        ContractHash::from_formatted_str(&token_contract_string).unwrap();
+    let offerer_account_string: String = runtime::get_named_arg(ARG_OFFERER);
+    let offerer_account_hash: AccountHash =
+        AccountHash::from_formatted_str(&offerer_account_string).unwrap();
+    let token_id = TokenIdentifier::from_args();
+
+    let key = get_offer_key(token_contract_hash, &token_id, offerer_account_hash);
+    runtime::ret(CLValue::from_t(get_offer_data(&key).fields()).unwrap_or_revert())
+}
+
+#[no_mangle]
+pub extern "C" fn get_auction() {
+    // Read args
+    let token_contract_string: String = runtime::get_named_arg(ARG_TOKEN_CONTRACT);
+    let token_contract_hash: ContractHash =
+        ContractHash::from_formatted_str(&token_contract_string).unwrap();
+    let token_id = TokenIdentifier::from_args();
+
+    let key = get_listing_key(token_contract_hash, &token_id);
+    let auction_data = get_auction_data(&key);
+
+    runtime::ret(CLValue::from_t(auction_data.fields()).unwrap_or_revert())
+}
+
+#[no_mangle]
+pub extern "C" fn get_dutch_auction() {
+    // Read args
+    let token_contract_string: String = runtime::get_named_arg(ARG_TOKEN_CONTRACT);
+    let token_contract_hash: ContractHash =
+        ContractHash::from_formatted_str(&token_contract_string).unwrap();
+    let token_id = TokenIdentifier::from_args();
+
+    let key = get_listing_key(token_contract_hash, &token_id);
+    runtime::ret(CLValue::from_t(get_dutch_auction_data(&key)).unwrap_or_revert())
+}
+
+#[no_mangle]
+pub extern "C" fn get_current_price() {
+    // Read args
+    let token_contract_string: String = runtime::get_named_arg(ARG_TOKEN_CONTRACT);
+    let token_contract_hash: ContractHash =
+        ContractHash::from_formatted_str(&token_contract_string).unwrap();
+    let token_id = TokenIdentifier::from_args();
+
+    let key = get_listing_key(token_contract_hash, &token_id);
+    let auction_data = get_dutch_auction_data(&key);
+    let price = get_dutch_auction_price(&auction_data, runtime::get_blocktime().into());
+    runtime::ret(CLValue::from_t(price).unwrap_or_revert())
+}
+
+#[no_mangle]
+pub extern "C" fn get_token_metadata() {
+    // Read args
+    let token_contract_string: String = runtime::get_named_arg(ARG_TOKEN_CONTRACT);
+    let token_contract_hash: ContractHash =
+        ContractHash::from_formatted_str(&token_contract_string).unwrap();
+    let token_id = TokenIdentifier::from_args();
+
+    let metadata = get_token_adapter(token_contract_hash).metadata(&token_id);
+    runtime::ret(CLValue::from_t(metadata).unwrap_or_revert())
+}
+
+#[no_mangle]
+pub extern "C" fn get_royalty() {
+    let token_contract_string: String = runtime::get_named_arg(ARG_TOKEN_CONTRACT);
+
+    runtime::ret(CLValue::from_t(get_royalty_data(&token_contract_string)).unwrap_or_revert())
+}
+
+#[no_mangle]
+pub extern "C" fn grant_role() {
+    // Get runtime args
+    let role: Role = Role::from_u8(runtime::get_named_arg(ARG_ROLE));
+    let account: Key = runtime::get_named_arg(ARG_ACCOUNT);
+
+    // Only admins can grant roles
+    require_role(Role::Admin);
+
+    set_role(role, account, true);
+
+    // Emit event
+    emit_role_granted(RoleGranted {
+        role: role as u8,
+        account,
+        sender: Key::Account(runtime::get_caller()),
+        timestamp: runtime::get_blocktime().into(),
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn revoke_role() {
+    // Get runtime args
+    let role: Role = Role::from_u8(runtime::get_named_arg(ARG_ROLE));
+    let account: Key = runtime::get_named_arg(ARG_ACCOUNT);
+
+    // Only admins can revoke roles
+    require_role(Role::Admin);
+
+    set_role(role, account, false);
+
+    // Emit event
+    emit_role_revoked(RoleRevoked {
+        role: role as u8,
+        account,
+        sender: Key::Account(runtime::get_caller()),
+        timestamp: runtime::get_blocktime().into(),
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn propose_admin() {
+    // Get runtime args
+    let new_admin: Key = runtime::get_named_arg(ARG_NEW_ADMIN);
+
+    // Only current admin can propose a successor
+    let current_admin: Key = get_installer();
+    if Key::Account(runtime::get_caller()) != current_admin {
+        runtime::revert(Error::CallerNotInstaller);
+    }
+
+    // Save pending admin, it takes over only after accepting
+    storage::write(get_pending_admin_uref(), Some(new_admin));
+
+    // Emit event
+    emit_admin_proposed(AdminProposed {
+        current_admin,
+        proposed_admin: new_admin,
+        timestamp: runtime::get_blocktime().into(),
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn accept_admin() {
+    let caller = Key::Account(runtime::get_caller());
+
+    // Only proposed account can accept
+    let pending_admin: Key = get_pending_admin().unwrap_or_revert_with(Error::NoAdminProposal);
+    if caller != pending_admin {
+        runtime::revert(Error::CallerNotPendingAdmin);
+    }
+
+    let previous_admin: Key = get_installer();
+
+    // Replace admin and clear proposal
+    storage::write(get_installer_uref(), caller);
+    storage::write(get_pending_admin_uref(), None::<Key>);
+
+    // Emit event
+    emit_admin_transferred(AdminTransferred {
+        previous_admin,
+        new_admin: caller,
+        timestamp: runtime::get_blocktime().into(),
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn cancel_admin_proposal() {
+    // Only current admin can cancel proposal
+    let current_admin: Key = get_installer();
+    if Key::Account(runtime::get_caller()) != current_admin {
+        runtime::revert(Error::CallerNotInstaller);
+    }
+
+    let proposed_admin: Key = get_pending_admin().unwrap_or_revert_with(Error::NoAdminProposal);
+
+    // Clear proposal
+    storage::write(get_pending_admin_uref(), None::<Key>);
+
+    // Emit event
+    emit_admin_proposal_cancelled(AdminProposalCancelled {
+        current_admin,
+        proposed_admin,
+        timestamp: runtime::get_blocktime().into(),
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn pause() {
+    // Get runtime args
+    let features: u8 = runtime::get_named_arg(ARG_FEATURES);
+
+    // Only pausers can pause
+    require_role(Role::Pauser);
+
+    // Add features to paused ones
+    let paused_features = get_paused_features() | features;
+    storage::write(get_paused_features_uref(), paused_features);
+
+    // Emit event
+    emit_paused(Paused {
+        features,
+        paused_features,
+        sender: Key::Account(runtime::get_caller()),
+        timestamp: runtime::get_blocktime().into(),
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn unpause() {
+    // Get runtime args
+    let features: u8 = runtime::get_named_arg(ARG_FEATURES);
+
+    // Only pausers can unpause
+    require_role(Role::Pauser);
+
+    // Remove features from paused ones
+    let paused_features = get_paused_features() & !features;
+    storage::write(get_paused_features_uref(), paused_features);
+
+    // Emit event
+    emit_unpaused(Unpaused {
+        features,
+        paused_features,
+        sender: Key::Account(runtime::get_caller()),
+        timestamp: runtime::get_blocktime().into(),
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn deposit() {
+    // Read args
+    let purse: URef = runtime::get_named_arg(ARG_BUY_PURSE);
+
+    // Credit whole purse balance to caller escrow
+    deposit_to_escrow(runtime::get_caller(), purse);
+}
+
+#[no_mangle]
+pub extern "C" fn withdraw() {
+    // Read args
+    let caller = runtime::get_caller();
+    let amount: Option<U512> = runtime::get_named_arg(ARG_AMOUNT);
+    let payment_token: Option<ContractHash> = get_payment_token();
+
+    // Withdraw whole pending balance if amount is not specified
+    let amount = amount.unwrap_or_else(|| get_escrow_balance(caller, payment_token));
+
+    withdraw_from_escrow(caller, payment_token, amount);
+}
+
+#[no_mangle]
+pub extern "C" fn get_pending_balance() {
+    let account: Key = runtime::get_named_arg(ARG_ACCOUNT);
+    let payment_token: Option<ContractHash> = get_payment_token();
+
+    let balance = get_escrow_balance(account.into_account().unwrap_or_revert(), payment_token);
+    runtime::ret(CLValue::from_t(balance).unwrap_or_revert())
+}
+
 #[no_mangle]
 pub extern "C" fn init() -> () {
     // Set up CES events
     init_events();
 
-    // Init reusable purse
-    get_purse(PURSE_REUSABLE);
+    init_named_keys();
 }
 
 #[no_mangle]
-pub extern "C" fn get_reusable_purse() -> () {
-    runtime::ret(CLValue::from_t(get_purse(PURSE_REUSABLE)).unwrap_or_revert())
+pub extern "C" fn migrate() {
+    // Only admin upgrading the package can migrate
+    if Key::Account(runtime::get_caller()) != get_installer() {
+        runtime::revert(Error::CallerNotInstaller);
+    }
+
+    let previous_contract_hash: ContractHash = runtime::get_named_arg(ARG_PREVIOUS_CONTRACT_HASH);
+
+    if runtime::get_key(KEY_ESCROWED_TOKENS).is_some() {
+        // Previous version is still enabled and moves tokens it holds over to this one
+        runtime::call_contract::<()>(
+            previous_contract_hash,
+            "hand_over_escrowed_tokens",
+            runtime_args! {},
+        );
+    } else {
+        // First version didn't record tokens it holds and can't hand them over, so upgrade waits
+        // until it holds none
+        require_no_first_version_escrow(previous_contract_hash);
+    }
+
+    // Store schemas of events added in this version
+    update_events();
+
+    // Create keys added in this version, existing ones are left untouched
+    init_named_keys();
+}
+
+/// Reverts while first version holds CEP-78 tokens. It only ever took tokens into auctions, so
+/// collections named by auction events are the ones to check.
+fn require_no_first_version_escrow(previous_contract_hash: ContractHash) {
+    for token_contract_hash in get_auctioned_collections() {
+        if detect_token_standard(token_contract_hash) == TokenStandard::Cep78
+            && get_cep78_balance(token_contract_hash, previous_contract_hash.into()) > 0
+        {
+            runtime::revert(Error::TokensInEscrow);
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn hand_over_escrowed_tokens() {
+    // Only a newer version of this package can take over tokens while migrating
+    let call_stack = runtime::get_call_stack();
+    let caller_contract_hash = match call_stack.iter().rev().nth(1) {
+        Some(CallStackElement::StoredContract {
+            contract_package_hash: caller_package_hash,
+            contract_hash: caller_contract_hash,
+        }) if *caller_package_hash == contract_package_hash() => *caller_contract_hash,
+        _ => runtime::revert(Error::PermissionDenied),
+    };
+
+    transfer_escrowed_tokens(caller_contract_hash);
+}
+
+fn init_named_keys() {
+    // Init escrow purse
+    get_purse(PURSE_ESCROW);
+
+    // Init fee settings
+    get_marketplace_fee_uref();
+    get_fee_recipient_uref();
+
+    // Init admin transfer
+    get_pending_admin_uref();
+
+    // Init pause flags
+    get_paused_features_uref();
+
+    // Init curation, marketplace starts in open mode
+    get_curated_mode_uref();
+
+    // Init bundle ids
+    get_bundle_counter_uref();
+
+    // Init auction anti-sniping default
+    get_auction_extension_uref();
+
+    // Init escrowed token count
+    get_escrowed_tokens_uref();
 }
 
 #[no_mangle]
 pub extern "C" fn call() {
+    // Installing again with the package already in account named keys upgrades it
+    match runtime::get_key(CONTRACT_PACKAGE_NAME) {
+        Some(package_key) => upgrade(package_key),
+        None => install(),
+    }
+}
+
+fn install() {
     let mut named_keys = NamedKeys::new();
     named_keys.insert(KEY_INSTALLER.to_string(), get_installer_uref().into());
 
@@ -572,3 +2122,45 @@ pub extern "C" fn call() {
     // Call init 
     runtime::call_contract::<()>(stored_contract_hash, "init", runtime_args! {});
 }
+
+fn upgrade(package_key: Key) {
+    let package_hash: ContractPackageHash = package_key
+        .into_hash()
+        .map(ContractPackageHash::new)
+        .unwrap_or_revert();
+    let previous_contract_hash: ContractHash = runtime::get_key(CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .unwrap_or_revert();
+
+    // New version inherits named keys of the previous one, so listings, offers, auctions,
+    // royalties, purses and settings stay in place. CEP-78 approvals name the contract hash, so
+    // sellers re-approve the new version before their CEP-78 listings can be bought.
+    let (stored_contract_hash, contract_version) =
+        storage::add_contract_version(package_hash, get_entry_points(), NamedKeys::new());
+
+    // Call migrate while previous version is enabled, so it can hand over escrowed tokens
+    runtime::call_contract::<()>(
+        stored_contract_hash,
+        "migrate",
+        runtime_args! {
+            ARG_PREVIOUS_CONTRACT_HASH => previous_contract_hash
+        },
+    );
+
+    // Previous version must not be used anymore
+    storage::disable_contract_version(package_hash, previous_contract_hash).unwrap_or_revert();
+
+    // Bump version
+    match runtime::get_key(CONTRACT_VERSION_KEY) {
+        Some(version_key) => storage::write(
+            version_key.into_uref().unwrap_or_revert(),
+            contract_version,
+        ),
+        None => runtime::put_key(
+            CONTRACT_VERSION_KEY,
+            storage::new_uref(contract_version).into(),
+        ),
+    }
+    runtime::put_key(CONTRACT_KEY, stored_contract_hash.into());
+}
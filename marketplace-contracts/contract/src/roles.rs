@@ -0,0 +1,63 @@
+use alloc::{format, string::String};
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{Key, URef};
+
+use crate::{
+    error::Error,
+    utils::{get_dictionary_uref, get_installer},
+};
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum Role {
+    Admin = 0,
+    RoyaltyManager = 1,
+    FeeManager = 2,
+    Pauser = 3,
+    Moderator = 4,
+}
+
+impl Role {
+    pub fn from_u8(value: u8) -> Role {
+        match value {
+            0 => Role::Admin,
+            1 => Role::RoyaltyManager,
+            2 => Role::FeeManager,
+            3 => Role::Pauser,
+            4 => Role::Moderator,
+            _ => runtime::revert(Error::InvalidRole),
+        }
+    }
+}
+
+pub fn get_roles_dictionary() -> URef {
+    get_dictionary_uref("roles")
+}
+
+pub fn get_role_key(role: Role, account: Key) -> String {
+    let key_string = format!("{}_{}", role as u8, account.to_formatted_string());
+    let hashed = runtime::blake2b(key_string);
+    hex::encode(hashed)
+}
+
+pub fn is_role_member(role: Role, account: Key) -> bool {
+    match storage::dictionary_get::<bool>(get_roles_dictionary(), &get_role_key(role, account)) {
+        Ok(Some(member)) => member,
+        _ => false,
+    }
+}
+
+// Installer and admins hold every role
+pub fn has_role(role: Role, account: Key) -> bool {
+    account == get_installer() || is_role_member(Role::Admin, account) || is_role_member(role, account)
+}
+
+pub fn require_role(role: Role) {
+    if !has_role(role, Key::Account(runtime::get_caller())) {
+        runtime::revert(Error::MissingRole);
+    }
+}
+
+pub fn set_role(role: Role, account: Key, member: bool) {
+    storage::dictionary_put(get_roles_dictionary(), &get_role_key(role, account), member)
+}
@@ -1,31 +1,263 @@
-use casper_types::{account::AccountHash, Key, U512};
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{self, FromBytes},
+    ContractHash, Key, U256, U512,
+};
 use casper_types_derive::{CLTyped, FromBytes, ToBytes};
-use alloc::vec::Vec;
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
 
-#[derive(CLTyped, ToBytes, FromBytes)]
+use crate::{
+    constants::{
+        DEFAULT_EXTENSION_MINUTES, DEFAULT_EXTENSION_WINDOW_MINUTES, DEFAULT_MAX_EXTENSIONS,
+    },
+    utils::minutes_to_milis,
+};
+
+// Fields are only ever appended, entries stored before a field existed end early and get default
+fn from_bytes_or<T: FromBytes>(bytes: &[u8], default: T) -> Result<(T, &[u8]), bytesrepr::Error> {
+    if bytes.is_empty() {
+        Ok((default, bytes))
+    } else {
+        T::from_bytes(bytes)
+    }
+}
+
+// Getters return entries as named fields, so callers don't depend on stored byte layout. Unset
+// optional fields are left out.
+pub type Fields = BTreeMap<String, String>;
+
+fn insert_field<T: ToString>(fields: &mut Fields, name: &str, value: Option<T>) {
+    if let Some(value) = value {
+        fields.insert(name.to_string(), value.to_string());
+    }
+}
+
+#[derive(CLTyped, ToBytes)]
 pub struct ListingData {
     pub seller: Key,
     pub price: U512,
     pub expiration_time: Option<u64>,
+    pub payment_token: Option<ContractHash>,
+    pub start_time: u64,
+}
+
+impl FromBytes for ListingData {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (seller, bytes) = Key::from_bytes(bytes)?;
+        let (price, bytes) = U512::from_bytes(bytes)?;
+        let (expiration_time, bytes) = Option::<u64>::from_bytes(bytes)?;
+        let (payment_token, bytes) = from_bytes_or(bytes, None)?;
+        let (start_time, bytes) = from_bytes_or(bytes, 0)?;
+
+        let listing = ListingData {
+            seller,
+            price,
+            expiration_time,
+            payment_token,
+            start_time,
+        };
+        Ok((listing, bytes))
+    }
 }
 
+impl ListingData {
+    pub fn fields(&self) -> Fields {
+        let mut fields = Fields::new();
+        insert_field(&mut fields, "seller", Some(self.seller.to_formatted_string()));
+        insert_field(&mut fields, "price", Some(self.price));
+        insert_field(&mut fields, "expiration_time", self.expiration_time);
+        insert_field(
+            &mut fields,
+            "payment_token",
+            self.payment_token.map(|token| token.to_formatted_string()),
+        );
+        insert_field(&mut fields, "start_time", Some(self.start_time));
+        fields
+    }
+}
+
+// Tokens of a bundle are sold together, i-th contract hash belongs to i-th token id, which is
+// replaced by i-th token hash if there is one
 #[derive(CLTyped, ToBytes, FromBytes)]
+pub struct BundleData {
+    pub seller: Key,
+    pub contract_hashes: Vec<ContractHash>,
+    pub token_ids: Vec<U256>,
+    pub price: U512,
+    pub expiration_time: Option<u64>,
+    pub payment_token: Option<ContractHash>,
+    pub token_hashes: Vec<Option<String>>,
+}
+
+// Reserve is stored as hex blake2b commitment of reserve price bytes followed by salt, seller reveals
+// both when auction ends. Bids raise current bid by the larger of both increments.
+#[derive(CLTyped, ToBytes)]
 pub struct AuctionData {
     pub seller: AccountHash,
     pub starting_price: U512,
     pub current_bid: U512,
     pub current_winner: AccountHash,
     pub end_time: u64,
+    pub payment_token: Option<ContractHash>,
+    pub reserve_commitment: Option<String>,
+    pub min_increment: U512,
+    pub min_increment_basis_points: u64,
+    pub extension: AuctionExtension,
+    pub extension_count: u64,
+    pub buy_now_price: Option<U512>,
+    pub start_time: u64,
+}
+
+impl FromBytes for AuctionData {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (seller, bytes) = AccountHash::from_bytes(bytes)?;
+        let (starting_price, bytes) = U512::from_bytes(bytes)?;
+        let (current_bid, bytes) = U512::from_bytes(bytes)?;
+        let (current_winner, bytes) = AccountHash::from_bytes(bytes)?;
+        let (end_time, bytes) = u64::from_bytes(bytes)?;
+        let (payment_token, bytes) = from_bytes_or(bytes, None)?;
+        let (reserve_commitment, bytes) = from_bytes_or(bytes, None)?;
+        let (min_increment, bytes) = from_bytes_or(bytes, U512::zero())?;
+        let (min_increment_basis_points, bytes) = from_bytes_or(bytes, 0)?;
+        let (extension, bytes) = from_bytes_or(bytes, AuctionExtension::legacy())?;
+        let (extension_count, bytes) = from_bytes_or(bytes, 0)?;
+        let (buy_now_price, bytes) = from_bytes_or(bytes, None)?;
+        let (start_time, bytes) = from_bytes_or(bytes, 0)?;
+
+        let auction = AuctionData {
+            seller,
+            starting_price,
+            current_bid,
+            current_winner,
+            end_time,
+            payment_token,
+            reserve_commitment,
+            min_increment,
+            min_increment_basis_points,
+            extension,
+            extension_count,
+            buy_now_price,
+            start_time,
+        };
+        Ok((auction, bytes))
+    }
 }
 
+impl AuctionData {
+    pub fn fields(&self) -> Fields {
+        let mut fields = Fields::new();
+        insert_field(&mut fields, "seller", Some(self.seller.to_formatted_string()));
+        insert_field(&mut fields, "starting_price", Some(self.starting_price));
+        insert_field(&mut fields, "current_bid", Some(self.current_bid));
+        insert_field(
+            &mut fields,
+            "current_winner",
+            Some(self.current_winner.to_formatted_string()),
+        );
+        insert_field(&mut fields, "end_time", Some(self.end_time));
+        insert_field(
+            &mut fields,
+            "payment_token",
+            self.payment_token.map(|token| token.to_formatted_string()),
+        );
+        insert_field(&mut fields, "reserve_commitment", self.reserve_commitment.as_ref());
+        insert_field(&mut fields, "min_increment", Some(self.min_increment));
+        insert_field(
+            &mut fields,
+            "min_increment_basis_points",
+            Some(self.min_increment_basis_points),
+        );
+        insert_field(&mut fields, "extension_window", Some(self.extension.window));
+        insert_field(&mut fields, "extension_length", Some(self.extension.length));
+        insert_field(&mut fields, "max_extensions", Some(self.extension.max_extensions));
+        insert_field(&mut fields, "extension_count", Some(self.extension_count));
+        insert_field(&mut fields, "buy_now_price", self.buy_now_price);
+        insert_field(&mut fields, "start_time", Some(self.start_time));
+        fields
+    }
+}
+
+// Bid placed less than window before end moves end to length after bid, at most max extensions times
+#[derive(CLTyped, ToBytes, FromBytes)]
+pub struct AuctionExtension {
+    pub window: u64,
+    pub length: u64,
+    pub max_extensions: u64,
+}
+
+impl AuctionExtension {
+    // Auctions stored before extensions existed kept extending by 10 minutes on late bids, they
+    // still do, now with default cap
+    pub fn legacy() -> Self {
+        AuctionExtension {
+            window: minutes_to_milis(DEFAULT_EXTENSION_WINDOW_MINUTES),
+            length: minutes_to_milis(DEFAULT_EXTENSION_MINUTES),
+            max_extensions: DEFAULT_MAX_EXTENSIONS,
+        }
+    }
+}
+
+// Price falls from start to floor price between start and end time, linearly if step interval is 0
 #[derive(CLTyped, ToBytes, FromBytes)]
+pub struct DutchAuctionData {
+    pub seller: AccountHash,
+    pub start_price: U512,
+    pub floor_price: U512,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub step_interval: u64,
+    pub payment_token: Option<ContractHash>,
+}
+
+#[derive(CLTyped, ToBytes)]
 pub struct OfferData {
     pub price: U512,
     pub expiration_time: u64,
+    pub payment_token: Option<ContractHash>,
+}
+
+impl FromBytes for OfferData {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (price, bytes) = U512::from_bytes(bytes)?;
+        let (expiration_time, bytes) = u64::from_bytes(bytes)?;
+        let (payment_token, bytes) = from_bytes_or(bytes, None)?;
+
+        let offer = OfferData {
+            price,
+            expiration_time,
+            payment_token,
+        };
+        Ok((offer, bytes))
+    }
+}
+
+impl OfferData {
+    pub fn fields(&self) -> Fields {
+        let mut fields = Fields::new();
+        insert_field(&mut fields, "price", Some(self.price));
+        insert_field(&mut fields, "expiration_time", Some(self.expiration_time));
+        insert_field(
+            &mut fields,
+            "payment_token",
+            self.payment_token.map(|token| token.to_formatted_string()),
+        );
+        fields
+    }
 }
 
 #[derive(CLTyped, ToBytes, FromBytes)]
 pub struct RoyaltyData {
     pub percentage: u64,
     pub creator: Key,
-}
\ No newline at end of file
+}
+
+#[derive(Clone, Copy, CLTyped, ToBytes, FromBytes)]
+pub struct CollectionData {
+    pub token_standard: u8,
+    pub identifier_mode: u8,
+    pub verified: bool,
+}
@@ -1,7 +1,7 @@
 use alloc::{
     format, str,
     string::{String, ToString},
-    vec,
+    vec::Vec,
 };
 use casper_contract::{
     contract_api::{
@@ -9,15 +9,34 @@ use casper_contract::{
         storage,
         system,
     },
+    ext_ffi,
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
-    account::AccountHash, runtime_args, system::CallStackElement, ContractHash,
-    ContractPackageHash, Key, RuntimeArgs, URef, U256, U512,
+    account::AccountHash,
+    api_error,
+    bytesrepr::{FromBytes, ToBytes},
+    runtime_args,
+    system::CallStackElement,
+    ApiError, CLTyped, ContractHash, ContractPackageHash, Key, RuntimeArgs, URef, U256, U512,
 };
 
-use crate::constants::{ARG_TOKEN_STANDARD, KEY_INSTALLER};
-use crate::{error::Error, AuctionData, ListingData, OfferData, RoyaltyData};
+use crate::constants::{
+    ARG_BUY_PURSE, ARG_PAYMENT_TOKEN, ARG_RESERVE_PRICE, ARG_RESERVE_SALT, ARG_START_TIME,
+    DEFAULT_EXTENSION_MINUTES, DEFAULT_EXTENSION_WINDOW_MINUTES,
+    DEFAULT_MARKETPLACE_FEE_BASIS_POINTS, DEFAULT_MAX_EXTENSIONS, KEY_AUCTION_EXTENSION,
+    KEY_FEE_RECIPIENT, KEY_INSTALLER, KEY_MARKETPLACE_FEE, KEY_PAUSED_FEATURES, KEY_PENDING_ADMIN,
+    MAX_BASIS_POINTS, MAX_EXTENSION_MINUTES, PURSE_ESCROW, RESERVE_REVEAL_MINUTES,
+};
+use crate::{
+    adapters::TokenIdentifier,
+    error::Error,
+    events::{
+        emit_funds_credited, emit_funds_deposited, emit_funds_spent, emit_funds_withdrawn,
+        FundsCredited, FundsDeposited, FundsSpent, FundsWithdrawn,
+    },
+    AuctionData, AuctionExtension, ListingData, OfferData, RoyaltyData,
+};
 
 pub fn contract_package_hash() -> ContractPackageHash {
     let call_stacks = runtime::get_call_stack();
@@ -49,112 +68,188 @@ pub fn contract_hash() -> ContractHash {
     hash
 }
 
-pub fn transfer_approved(token_contract_hash: ContractHash, token_id: U256, owner: Key) -> bool {
-    let token_standard: u8 = runtime::get_named_arg("token_standard");
-
-    if (token_standard == 0u8) {
-        let approved = runtime::call_contract::<Option<Key>>(
-            token_contract_hash,
-            "get_approved",
-            runtime_args! {
-            "owner" => owner,
-            "token_id" => token_id
-            },
-        )
-        .unwrap_or_revert_with(Error::NeedsTransferApproval);
-        let approved_hash = approved.into_hash().unwrap_or_revert();
-
-        contract_package_hash().value() == approved_hash
-    } else if token_standard == 1u8 {
-        let approved = runtime::call_contract::<Option<Key>>(
-            token_contract_hash,
-            "get_approved",
-            runtime_args! {
-            "owner" => owner,
-            "token_id" => token_id.as_u64()
-            },
+pub fn get_optional_named_arg<T: CLTyped + FromBytes>(name: &str) -> Option<T> {
+    let mut arg_size: usize = 0;
+    let ret = unsafe {
+        ext_ffi::casper_get_named_arg_size(
+            name.as_bytes().as_ptr(),
+            name.len(),
+            &mut arg_size as *mut usize,
         )
-        .unwrap_or_revert();
+    };
 
-        let approved_hash = approved.into_hash().unwrap_or_revert_with(4);
+    match api_error::result_from(ret) {
+        Ok(_) => Some(runtime::get_named_arg(name)),
+        Err(ApiError::MissingArgument) => None,
+        Err(error) => runtime::revert(error),
+    }
+}
 
-        contract_hash().value() == approved_hash
-    } else {
-        false
+pub fn get_dictionary_uref(key: &str) -> URef {
+    match runtime::get_key(key) {
+        Some(uref_key) => uref_key.into_uref().unwrap_or_revert(),
+        None => storage::new_dictionary(key).unwrap_or_revert(),
     }
 }
 
-pub fn get_token_owner(token_contract_hash: ContractHash, token_id: U256) -> Key {
-    let token_standard: u8 = runtime::get_named_arg("token_standard");
+pub fn get_installer_uref() -> URef {
+    match runtime::get_key(KEY_INSTALLER) {
+        Some(uref_key) => uref_key.into_uref().unwrap_or_revert(),
+        None => storage::new_uref(Key::Account(runtime::get_caller())),
+    }
+}
 
-    if token_standard == 0u8 {
-        runtime::call_contract::<Option<Key>>(
-            token_contract_hash,
-            "owner_of",
-            runtime_args! {
-              "token_id" => token_id
-            },
-        )
+// Current admin, installer at first and replaced through accept_admin
+pub fn get_installer() -> Key {
+    storage::read(get_installer_uref())
         .unwrap_or_revert()
-    } else {
-        runtime::call_contract::<Key>(
-            token_contract_hash,
-            "owner_of",
-            runtime_args! {
-              "token_id" => token_id.as_u64()
-            },
-        )
+        .unwrap_or_revert()
+}
+
+pub fn get_pending_admin_uref() -> URef {
+    match runtime::get_key(KEY_PENDING_ADMIN) {
+        Some(uref_key) => uref_key.into_uref().unwrap_or_revert(),
+        None => {
+            let uref = storage::new_uref(None::<Key>);
+            runtime::put_key(KEY_PENDING_ADMIN, uref.into());
+            uref
+        }
     }
 }
 
-pub fn transfer_token(contract_hash: ContractHash, owner: Key, to: Key, token_id: U256) -> () {
-    let token_standard: u8 = runtime::get_named_arg("token_standard");
+pub fn get_pending_admin() -> Option<Key> {
+    storage::read(get_pending_admin_uref())
+        .unwrap_or_revert()
+        .unwrap_or_revert()
+}
 
-    if token_standard == 0u8 {
-        runtime::call_contract::<()>(
-            contract_hash,
-            "transfer_from",
-            runtime_args! {
-              "sender" => owner,
-              "recipient" => to,
-              "token_ids" => vec![token_id],
-            },
-        )
-    } else {
-        runtime::call_contract::<()>(
-            contract_hash,
-            "transfer",
-            runtime_args! {
-              "source_key" => owner,
-              "target_key" => to,
-              "token_id" =>
-              token_id.as_u64(),
-            },
-        )
+pub fn get_marketplace_fee_uref() -> URef {
+    match runtime::get_key(KEY_MARKETPLACE_FEE) {
+        Some(uref_key) => uref_key.into_uref().unwrap_or_revert(),
+        None => {
+            let uref = storage::new_uref(DEFAULT_MARKETPLACE_FEE_BASIS_POINTS);
+            runtime::put_key(KEY_MARKETPLACE_FEE, uref.into());
+            uref
+        }
     }
 }
 
-pub fn get_dictionary_uref(key: &str) -> URef {
-    match runtime::get_key(key) {
+pub fn get_marketplace_fee() -> u64 {
+    storage::read(get_marketplace_fee_uref())
+        .unwrap_or_revert()
+        .unwrap_or_revert()
+}
+
+// Marketplace default for auctions started without own extension settings
+pub fn get_auction_extension_uref() -> URef {
+    match runtime::get_key(KEY_AUCTION_EXTENSION) {
         Some(uref_key) => uref_key.into_uref().unwrap_or_revert(),
-        None => storage::new_dictionary(key).unwrap_or_revert(),
+        None => {
+            let uref = storage::new_uref(AuctionExtension {
+                window: minutes_to_milis(DEFAULT_EXTENSION_WINDOW_MINUTES),
+                length: minutes_to_milis(DEFAULT_EXTENSION_MINUTES),
+                max_extensions: DEFAULT_MAX_EXTENSIONS,
+            });
+            runtime::put_key(KEY_AUCTION_EXTENSION, uref.into());
+            uref
+        }
     }
 }
 
-pub fn get_installer_uref() -> URef {
-    match runtime::get_key(KEY_INSTALLER) {
+pub fn get_auction_extension() -> AuctionExtension {
+    storage::read(get_auction_extension_uref())
+        .unwrap_or_revert()
+        .unwrap_or_revert()
+}
+
+pub fn get_fee_recipient_uref() -> URef {
+    match runtime::get_key(KEY_FEE_RECIPIENT) {
         Some(uref_key) => uref_key.into_uref().unwrap_or_revert(),
-        None => storage::new_uref(Key::Account(runtime::get_caller())),
+        None => {
+            let uref = storage::new_uref(None::<Key>);
+            runtime::put_key(KEY_FEE_RECIPIENT, uref.into());
+            uref
+        }
     }
 }
 
-pub fn get_installer() -> Key {
-    storage::read(get_installer_uref())
+// Fees go to installer unless a separate recipient was set
+pub fn get_fee_recipient() -> Key {
+    let recipient: Option<Key> = storage::read(get_fee_recipient_uref())
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+
+    recipient.unwrap_or_else(get_installer)
+}
+
+pub fn get_collection_fees_dictionary() -> URef {
+    get_dictionary_uref("collection_fees")
+}
+
+pub fn get_collection_fee_override(token_contract_hash_string: &str) -> Option<u64> {
+    match storage::dictionary_get::<Option<u64>>(
+        get_collection_fees_dictionary(),
+        token_contract_hash_string,
+    ) {
+        Ok(Some(fee_basis_points)) => fee_basis_points,
+        _ => None,
+    }
+}
+
+// Collection override if set, global marketplace fee otherwise
+pub fn resolve_marketplace_fee(token_contract_hash_string: &str) -> u64 {
+    get_collection_fee_override(token_contract_hash_string).unwrap_or_else(get_marketplace_fee)
+}
+
+// Royalty is in percent and fee in basis points, together they can't exceed whole price
+pub fn require_valid_fee_split(royalty_percentage: u64, fee_basis_points: u64) {
+    let total = royalty_percentage
+        .checked_mul(MAX_BASIS_POINTS / 100)
+        .and_then(|royalty_basis_points| royalty_basis_points.checked_add(fee_basis_points));
+
+    if total.map_or(true, |total| total > MAX_BASIS_POINTS) {
+        runtime::revert(Error::FeeExceedsPrice);
+    }
+}
+
+pub fn get_paused_features_uref() -> URef {
+    match runtime::get_key(KEY_PAUSED_FEATURES) {
+        Some(uref_key) => uref_key.into_uref().unwrap_or_revert(),
+        None => {
+            let uref = storage::new_uref(0u8);
+            runtime::put_key(KEY_PAUSED_FEATURES, uref.into());
+            uref
+        }
+    }
+}
+
+pub fn get_paused_features() -> u8 {
+    storage::read(get_paused_features_uref())
         .unwrap_or_revert()
         .unwrap_or_revert()
 }
 
-pub fn get_listing_key(token_contract_hash: ContractHash, token_id: U256) -> String {
+pub fn require_not_paused(feature: u8) {
+    if get_paused_features() & feature != 0 {
+        runtime::revert(Error::MarketplacePaused);
+    }
+}
+
+// Sales scheduled for later start at given time, otherwise right away
+pub fn get_start_time(current_time: u64) -> u64 {
+    match get_optional_named_arg::<u64>(ARG_START_TIME) {
+        Some(start_time) => start_time.max(current_time),
+        None => current_time,
+    }
+}
+
+pub fn require_started(start_time: u64) {
+    if u64::from(runtime::get_blocktime()) < start_time {
+        runtime::revert(Error::NotStarted);
+    }
+}
+
+pub fn get_listing_key(token_contract_hash: ContractHash, token_id: &TokenIdentifier) -> String {
     let key_string = format!(
         "{}_{}",
         token_contract_hash.to_string(),
@@ -174,7 +269,7 @@ pub fn get_royalties_dictionary() -> URef {
 
 pub fn get_offer_key(
     token_contract_hash: ContractHash,
-    token_id: U256,
+    token_id: &TokenIdentifier,
     bidder: AccountHash,
 ) -> String {
     let key_string = format!(
@@ -194,6 +289,125 @@ pub fn get_auction_dictionary() -> URef {
     get_dictionary_uref("auctions")
 }
 
+pub fn get_escrow_dictionary() -> URef {
+    get_dictionary_uref("escrow")
+}
+
+// Native balances are keyed by account only, CEP-18 ones by account and token
+pub fn get_escrow_key(account: AccountHash, payment_token: Option<ContractHash>) -> String {
+    match payment_token {
+        None => hex::encode(account.value()),
+        Some(token) => {
+            let key_string = format!("{}_{}", account, token);
+            let hashed = runtime::blake2b(key_string);
+            hex::encode(hashed)
+        }
+    }
+}
+
+pub fn get_escrow_balance(account: AccountHash, payment_token: Option<ContractHash>) -> U512 {
+    let key = get_escrow_key(account, payment_token);
+    match storage::dictionary_get::<U512>(get_escrow_dictionary(), &key) {
+        Ok(Some(balance)) => balance,
+        _ => U512::zero(),
+    }
+}
+
+fn set_escrow_balance(account: AccountHash, payment_token: Option<ContractHash>, balance: U512) {
+    let key = get_escrow_key(account, payment_token);
+    storage::dictionary_put(get_escrow_dictionary(), &key, balance)
+}
+
+// Moves the whole balance of the given purse into the escrow purse and credits it to the account
+pub fn deposit_to_escrow(account: AccountHash, from_purse: URef) -> U512 {
+    let amount: U512 = system::get_purse_balance(from_purse).unwrap_or_revert();
+
+    if amount > U512::zero() {
+        system::transfer_from_purse_to_purse(from_purse, get_purse(PURSE_ESCROW), amount, None)
+            .unwrap_or_revert();
+        set_escrow_balance(account, None, get_escrow_balance(account, None) + amount);
+
+        emit_funds_deposited(FundsDeposited {
+            account: Key::Account(account),
+            amount,
+            timestamp: runtime::get_blocktime().into(),
+        });
+    }
+
+    amount
+}
+
+// Credits trade proceeds or refunds, funds must already be held by marketplace
+pub fn credit_escrow(account: AccountHash, payment_token: Option<ContractHash>, amount: U512) {
+    let balance = get_escrow_balance(account, payment_token);
+    set_escrow_balance(account, payment_token, balance + amount);
+
+    emit_funds_credited(FundsCredited {
+        account: Key::Account(account),
+        payment_token,
+        amount,
+        timestamp: runtime::get_blocktime().into(),
+    });
+}
+
+fn debit_escrow(account: AccountHash, payment_token: Option<ContractHash>, amount: U512) {
+    let balance = get_escrow_balance(account, payment_token);
+    if balance < amount {
+        revert(Error::BalanceInsufficient);
+    }
+
+    set_escrow_balance(account, payment_token, balance - amount);
+}
+
+// Debits the account's escrow balance, funds stay in the escrow purse for the caller to move
+pub fn spend_from_escrow(account: AccountHash, payment_token: Option<ContractHash>, amount: U512) {
+    debit_escrow(account, payment_token, amount);
+
+    emit_funds_spent(FundsSpent {
+        account: Key::Account(account),
+        payment_token,
+        amount,
+        timestamp: runtime::get_blocktime().into(),
+    });
+}
+
+pub fn withdraw_from_escrow(
+    account: AccountHash,
+    payment_token: Option<ContractHash>,
+    amount: U512,
+) {
+    debit_escrow(account, payment_token, amount);
+
+    if amount == U512::zero() {
+        return;
+    }
+
+    match payment_token {
+        None => {
+            system::transfer_from_purse_to_account(get_purse(PURSE_ESCROW), account, amount, None)
+                .unwrap_or_revert();
+        }
+        Some(token) => cep18_transfer(token, Key::Account(account), amount),
+    }
+
+    emit_funds_withdrawn(FundsWithdrawn {
+        account: Key::Account(account),
+        payment_token,
+        amount,
+        timestamp: runtime::get_blocktime().into(),
+    });
+}
+
+// Takes exactly the amount out of the supplied purse through account's escrow, rest of the purse is sent back
+pub fn collect_payment(account: AccountHash, from_purse: URef, amount: U512) {
+    let deposited = deposit_to_escrow(account, from_purse);
+    spend_from_escrow(account, None, amount);
+
+    if deposited > amount {
+        withdraw_from_escrow(account, None, deposited - amount);
+    }
+}
+
 pub fn get_purse(purse_name: &str) -> URef {
     let purse = if !runtime::has_key(&purse_name) {
         let purse = system::create_purse();
@@ -246,71 +460,247 @@ pub fn get_auction_data(key: &str) -> AuctionData {
     auction
 }
 
-pub fn get_royalty_data(key: &str) -> RoyaltyData {
-    let royalty: RoyaltyData = match storage::dictionary_get(get_royalties_dictionary(), &key) {
-        Ok(item) => match item {
-            None => RoyaltyData {
-                percentage: 0u64,
-                creator: Key::Account(runtime::get_caller()),
-            },
-            Some(value) => value,
-        },
-        Err(_error) => runtime::revert(Error::AuctionCancelledOrFinished),
+// Bid close to end pushes end back, never earlier than it already is
+pub fn extend_auction(auction: &mut AuctionData, current_time: u64) {
+    let remaining_time = auction.end_time.saturating_sub(current_time);
+    let extended_end_time = current_time + auction.extension.length;
+
+    // Only bids that actually move the end use up an extension
+    if remaining_time < auction.extension.window
+        && extended_end_time > auction.end_time
+        && auction.extension_count < auction.extension.max_extensions
+    {
+        auction.end_time = extended_end_time;
+        auction.extension_count += 1;
+    }
+}
+
+// Window and length are capped at a day, enabled extensions must move the end
+pub fn require_valid_extension(extension: &AuctionExtension) {
+    let max_length = minutes_to_milis(MAX_EXTENSION_MINUTES);
+    if extension.window > max_length || extension.length > max_length {
+        runtime::revert(Error::InvalidExtension);
+    }
+    if extension.window > 0 && extension.max_extensions > 0 && extension.length == 0 {
+        runtime::revert(Error::InvalidExtension);
+    }
+}
+
+// Lowest accepted bid, at least one mote above current bid
+pub fn get_min_bid(auction: &AuctionData) -> U512 {
+    let relative_increment = auction.current_bid * U512::from(auction.min_increment_basis_points)
+        / U512::from(MAX_BASIS_POINTS);
+    let increment = auction.min_increment.max(relative_increment).max(U512::one());
+
+    auction.current_bid + increment
+}
+
+/// Hex blake2b of reserve price bytes followed by salt, seller keeps both secret until auction ends
+pub fn get_reserve_commitment(reserve_price: U512, salt: &str) -> String {
+    let mut bytes = reserve_price.to_bytes().unwrap_or_revert();
+    bytes.extend_from_slice(salt.as_bytes());
+    hex::encode(runtime::blake2b(bytes))
+}
+
+/// Commitment must be a blake2b hash, anything else could never be revealed
+pub fn require_valid_reserve_commitment(commitment: &str) {
+    match hex::decode(commitment) {
+        Ok(bytes) if bytes.len() == 32 => {}
+        _ => revert(Error::InvalidReservePrice),
+    }
+}
+
+/// Reads reserve reveal from args and checks it against commitment. Until reveal window passes
+/// ending waits for seller, afterwards unrevealed reserve counts as met, so seller can't back out
+/// of a sale by keeping quiet.
+pub fn is_reserve_met(auction: &AuctionData, current_time: u64) -> bool {
+    let commitment = match &auction.reserve_commitment {
+        Some(commitment) => commitment,
+        None => return true,
     };
 
-    royalty
+    match get_optional_named_arg::<U512>(ARG_RESERVE_PRICE) {
+        Some(reserve_price) => {
+            let salt: String = runtime::get_named_arg(ARG_RESERVE_SALT);
+            if get_reserve_commitment(reserve_price, &salt) != *commitment {
+                revert(Error::InvalidReservePrice);
+            }
+            auction.current_bid >= reserve_price
+        }
+        None if current_time < auction.end_time + minutes_to_milis(RESERVE_REVEAL_MINUTES) => {
+            revert(Error::ReserveNotRevealed)
+        }
+        None => true,
+    }
+}
+
+// None when no royalty was set for collection
+pub fn get_royalty_data(key: &str) -> Option<RoyaltyData> {
+    match storage::dictionary_get(get_royalties_dictionary(), key) {
+        Ok(royalty) => royalty,
+        Err(_error) => runtime::revert(Error::AuctionCancelledOrFinished),
+    }
 }
 
+// Price is split evenly between sold tokens, royalty and fee of each part follow its collection
 pub fn process_payment(
     price: U512,
-    from_purse: URef,
-    token_contract_hash_string: String,
+    funds: &Funds,
+    token_contract_hash_strings: &[String],
     to: Key,
 ) -> () {
-    let royalty: RoyaltyData = get_royalty_data(&token_contract_hash_string);
+    let items = token_contract_hash_strings.len();
+    let item_price = price / U512::from(items);
+
+    let mut seller_part = price;
+    let mut royalty_parts: Vec<(RoyaltyData, U512)> = Vec::new();
+    let mut marketplace_part = U512::zero();
+
+    for (index, token_contract_hash_string) in token_contract_hash_strings.iter().enumerate() {
+        // Last token takes rounding remainder
+        let part = if index + 1 == items {
+            price - item_price * U512::from(items - 1)
+        } else {
+            item_price
+        };
+
+        let royalty: Option<RoyaltyData> = get_royalty_data(token_contract_hash_string);
+        let royalty_percentage = royalty.as_ref().map_or(0, |royalty| royalty.percentage);
+        let creator_part = part * U512::from(royalty_percentage) / U512::from(100);
+        let fee_part = part * U512::from(resolve_marketplace_fee(token_contract_hash_string))
+            / U512::from(MAX_BASIS_POINTS);
+
+        // Global fee can be raised after royalty was set, so split is checked again here
+        seller_part = seller_part
+            .checked_sub(creator_part + fee_part)
+            .unwrap_or_revert_with(Error::FeeExceedsPrice);
+        marketplace_part += fee_part;
+        if let Some(royalty) = royalty.filter(|royalty| royalty.percentage > 0) {
+            royalty_parts.push((royalty, creator_part));
+        }
+    }
 
-    let creator_part = price * U512::from(royalty.percentage) / U512::from(100);
-    let marketplace_part = price * U512::from(10) / U512::from(100);
+    funds.pay(to, seller_part);
 
-    system::transfer_from_purse_to_account(
-        from_purse,
-        to.into_account().unwrap_or_revert(),
-        price - creator_part - marketplace_part,
-        None,
-    )
-    .unwrap_or_revert();
-
-    if (royalty.percentage > 0) {
-        system::transfer_from_purse_to_account(
-            from_purse,
-            royalty.creator.into_account().unwrap_or_revert(),
-            creator_part,
-            None,
-        )
-        .unwrap_or_revert();
+    for (royalty, creator_part) in royalty_parts {
+        funds.pay(royalty.creator, creator_part);
     }
 
-    system::transfer_from_purse_to_account(
-        from_purse,
-        get_installer().into_account().unwrap_or_revert(),
-        marketplace_part,
-        None,
-    )
-    .unwrap_or_revert();
+    if marketplace_part > U512::zero() {
+        funds.pay(get_fee_recipient(), marketplace_part);
+    }
 }
 
-pub fn minutes_to_milis(minutes: u64) -> u64 {
-    minutes * 60000
+/// Funds marketplace holds for a trade, either in a purse or as its CEP-18 token balance
+pub enum Funds {
+    Purse(URef),
+    Cep18(ContractHash),
 }
 
-pub fn get_transfer_marketplace_address() -> Key {
-    let token_standard: u8 = runtime::get_named_arg(ARG_TOKEN_STANDARD);
+impl Funds {
+    /// Native payments are held in given purse, CEP-18 ones by the marketplace package
+    pub fn new(payment_token: Option<ContractHash>, purse: URef) -> Funds {
+        match payment_token {
+            Some(token) => Funds::Cep18(token),
+            None => Funds::Purse(purse),
+        }
+    }
+
+    /// Takes exact amount from caller, native payments are read from buy purse arg
+    pub fn collect(&self, amount: U512) {
+        let caller = runtime::get_caller();
 
-    if token_standard == 0u8 {
-        contract_package_hash().into()
-    } else if token_standard == 1u8 {
-        contract_hash().into()
-    } else {
-        contract_hash().into()
+        // Caller must pay in trade's currency, no payment token means native tokens
+        if get_payment_token() != self.payment_token() {
+            revert(Error::PaymentTokenMismatch);
+        }
+
+        match self {
+            Funds::Purse(purse) => {
+                let buyer_purse: URef = runtime::get_named_arg(ARG_BUY_PURSE);
+                collect_payment(caller, buyer_purse, amount);
+
+                let escrow_purse = get_purse(PURSE_ESCROW);
+                if purse.addr() != escrow_purse.addr() {
+                    system::transfer_from_purse_to_purse(escrow_purse, *purse, amount, None)
+                        .unwrap_or_revert();
+                }
+            }
+            Funds::Cep18(token) => {
+                // Token trades take nothing from buy purse, anything session put there goes back
+                if let Some(buyer_purse) = get_optional_named_arg::<URef>(ARG_BUY_PURSE) {
+                    collect_payment(caller, buyer_purse, U512::zero());
+                }
+
+                runtime::call_contract::<()>(
+                    *token,
+                    "transfer_from",
+                    runtime_args! {
+                        "owner" => Key::Account(caller),
+                        "recipient" => Key::from(contract_package_hash()),
+                        "amount" => to_token_amount(amount)
+                    },
+                )
+            }
+        }
     }
+
+    pub fn payment_token(&self) -> Option<ContractHash> {
+        match self {
+            Funds::Purse(_) => None,
+            Funds::Cep18(token) => Some(*token),
+        }
+    }
+
+    /// Credits recipient's pending balance, recipient withdraws it later
+    pub fn pay(&self, recipient: Key, amount: U512) {
+        if amount == U512::zero() {
+            return;
+        }
+
+        let account = recipient.into_account().unwrap_or_revert();
+        match self {
+            Funds::Purse(purse) => {
+                let escrow_purse = get_purse(PURSE_ESCROW);
+                if purse.addr() != escrow_purse.addr() {
+                    system::transfer_from_purse_to_purse(*purse, escrow_purse, amount, None)
+                        .unwrap_or_revert();
+                }
+                credit_escrow(account, None, amount);
+            }
+            Funds::Cep18(token) => credit_escrow(account, Some(*token), amount),
+        }
+    }
+}
+
+fn cep18_transfer(token: ContractHash, recipient: Key, amount: U512) {
+    runtime::call_contract::<()>(
+        token,
+        "transfer",
+        runtime_args! {
+            "recipient" => recipient,
+            "amount" => to_token_amount(amount)
+        },
+    )
+}
+
+// Prices are kept in U512 for every currency, CEP-18 amounts are U256
+fn to_token_amount(amount: U512) -> U256 {
+    let mut bytes = [0u8; 64];
+    amount.to_little_endian(&mut bytes);
+
+    if bytes[32..].iter().any(|byte| *byte != 0) {
+        runtime::revert(Error::PaymentAmountOverflow);
+    }
+
+    U256::from_little_endian(&bytes[..32])
+}
+
+pub fn get_payment_token() -> Option<ContractHash> {
+    get_optional_named_arg::<String>(ARG_PAYMENT_TOKEN)
+        .map(|token| ContractHash::from_formatted_str(&token).unwrap())
+}
+
+pub fn minutes_to_milis(minutes: u64) -> u64 {
+    minutes * 60000
 }
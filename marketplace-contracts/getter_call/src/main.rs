@@ -0,0 +1,41 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, string::String};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{runtime_args, ContractHash, RuntimeArgs, U256};
+use session_utils::get_optional_named_arg;
+
+// Contract call results don't reach deploy caller, so entry returned by getter is stored under
+// caller's named key for off-chain reading
+#[no_mangle]
+pub extern "C" fn call() {
+    let marketplace_hash: String = runtime::get_named_arg("marketplace_hash");
+    let entry_point: String = runtime::get_named_arg("entry_point");
+    let contract_hash: String = runtime::get_named_arg("contract_hash");
+    let token_id: U256 = runtime::get_named_arg("token_id");
+
+    let contract_hash_parsed = ContractHash::from_formatted_str(&marketplace_hash).unwrap();
+
+    let mut args = runtime_args! {
+        "contract_hash" => contract_hash,
+        "token_id" => token_id
+    };
+    if let Some(offerer) = get_optional_named_arg::<String>("offerer") {
+        args.insert("offerer", offerer).unwrap_or_revert();
+    }
+
+    let fields: BTreeMap<String, String> =
+        runtime::call_contract(contract_hash_parsed, &entry_point, args);
+
+    runtime::put_key(&entry_point, storage::new_uref(fields).into());
+}
@@ -6,44 +6,54 @@ compile_error!("target arch should be wasm32: compile with '--target wasm32-unkn
 
 extern crate alloc;
 
-use alloc::{
-    string::{String, ToString},
-    vec::Vec,
-};
+use alloc::string::String;
 
-use casper_contract::{
-    contract_api::{account, runtime, storage, system},
-    unwrap_or_revert::UnwrapOrRevert,
-};
-use casper_types::{runtime_args, ContractHash, RuntimeArgs, URef, U256, U512};
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{runtime_args, ContractHash, RuntimeArgs, U256, U512};
+use session_utils::{get_optional_named_arg, get_trade_amount, insert_buy_purse};
 
 #[no_mangle]
 pub extern "C" fn call() {
-    let token_id: U256 = runtime::get_named_arg("token_id");
-    let contract_hash: String = runtime::get_named_arg("buy_contract_hash");
     let marketplace_hash: String = runtime::get_named_arg("marketplace_hash");
-    let standard: u8 = runtime::get_named_arg("token_standard");
     let amount: U512 = runtime::get_named_arg("amount");
 
+    let trade_amount = get_trade_amount(amount);
+
     let contract_hash_parsed = ContractHash::from_formatted_str(&marketplace_hash).unwrap();
 
-    let deposit_purse: URef = runtime::call_contract(
-        contract_hash_parsed,
-        "get_reusable_purse",
-        runtime_args! {},
-    );
-
-    system::transfer_from_purse_to_purse(account::get_main_purse(), deposit_purse, amount, None)
-        .unwrap_or_revert();
-
-    runtime::call_contract(
-        contract_hash_parsed,
-        "buy_listing",
-        runtime_args! {
-         "contract_hash" => contract_hash,
-         "token_id" => token_id,
-         "buy_purse" => deposit_purse,
-         "token_standard" => standard
-        },
-    )
+    // Deposit credits the whole purse to caller's escrow balance
+    if get_optional_named_arg::<bool>("deposit").unwrap_or(false) {
+        let mut args = RuntimeArgs::new();
+        insert_buy_purse(&mut args, amount);
+
+        return runtime::call_contract(contract_hash_parsed, "deposit", args);
+    }
+
+    // Bundles are bought by id
+    if let Some(bundle_id) = get_optional_named_arg::<u64>("bundle_id") {
+        let mut args = runtime_args! {
+            "bundle_id" => bundle_id,
+            "amount" => trade_amount
+        };
+        insert_buy_purse(&mut args, amount);
+
+        return runtime::call_contract(contract_hash_parsed, "buy_bundle", args);
+    }
+
+    let contract_hash: String = runtime::get_named_arg("buy_contract_hash");
+
+    let mut args = runtime_args! {
+        "contract_hash" => contract_hash,
+        "amount" => trade_amount
+    };
+    insert_buy_purse(&mut args, amount);
+
+    // Tokens of hash identified CEP-78 collections are passed by hash
+    match get_optional_named_arg::<String>("token_hash") {
+        Some(token_hash) => args.insert("token_hash", token_hash),
+        None => args.insert("token_id", runtime::get_named_arg::<U256>("token_id")),
+    }
+    .unwrap_or_revert();
+
+    runtime::call_contract(contract_hash_parsed, "buy_listing", args)
 }
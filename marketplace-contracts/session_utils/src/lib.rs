@@ -0,0 +1,55 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use casper_contract::{
+    contract_api::{account, runtime, system},
+    ext_ffi,
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{api_error, bytesrepr::FromBytes, ApiError, CLTyped, RuntimeArgs, URef, U512};
+
+pub fn get_optional_named_arg<T: CLTyped + FromBytes>(name: &str) -> Option<T> {
+    let mut arg_size: usize = 0;
+    let ret = unsafe {
+        ext_ffi::casper_get_named_arg_size(
+            name.as_bytes().as_ptr(),
+            name.len(),
+            &mut arg_size as *mut usize,
+        )
+    };
+
+    match api_error::result_from(ret) {
+        Ok(_) => Some(runtime::get_named_arg(name)),
+        Err(ApiError::MissingArgument) => None,
+        Err(error) => runtime::revert(error),
+    }
+}
+
+/// Session can't spend more than its amount arg, so purse funded above the traded amount names
+/// it separately and marketplace refunds the remainder
+pub fn get_trade_amount(amount: U512) -> U512 {
+    get_optional_named_arg::<U512>("trade_amount").unwrap_or(amount)
+}
+
+/// Creates purse funded with amount from caller's main purse
+pub fn create_buy_purse(amount: U512) -> URef {
+    let buy_purse: URef = system::create_purse();
+
+    system::transfer_from_purse_to_purse(account::get_main_purse(), buy_purse, amount, None)
+        .unwrap_or_revert();
+
+    buy_purse
+}
+
+/// Token priced trades are paid from CEP-18 allowance, so buy purse is only funded for native ones
+pub fn insert_buy_purse(args: &mut RuntimeArgs, amount: U512) {
+    if let Some(payment_token) = get_optional_named_arg::<String>("payment_token") {
+        args.insert("payment_token", payment_token).unwrap_or_revert();
+        return;
+    }
+
+    args.insert("buy_purse", create_buy_purse(amount)).unwrap_or_revert();
+}
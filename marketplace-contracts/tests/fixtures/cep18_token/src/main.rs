@@ -0,0 +1,188 @@
+//! Minimal CEP-18 token used by integration tests to pay in fungible tokens.
+//! Supports balances, allowances and transfers, nothing else.
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+
+use alloc::{string::String, vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::ToBytes, contracts::NamedKeys, system::CallStackElement, ApiError, CLType,
+    CLTyped, CLValue, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Key, Parameter,
+    URef, U256,
+};
+
+const BALANCES: &str = "balances";
+const ALLOWANCES: &str = "allowances";
+
+fn get_dictionary(name: &str) -> URef {
+    runtime::get_key(name)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert()
+}
+
+fn hashed_key(parts: &[&Key]) -> String {
+    let mut bytes = vec![];
+    for part in parts {
+        bytes.extend(part.to_bytes().unwrap_or_revert());
+    }
+    hex::encode(runtime::blake2b(bytes))
+}
+
+fn read_amount(dictionary: &str, key: &str) -> U256 {
+    storage::dictionary_get(get_dictionary(dictionary), key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+fn write_amount(dictionary: &str, key: &str, amount: U256) {
+    storage::dictionary_put(get_dictionary(dictionary), key, amount)
+}
+
+// Contracts are identified by their package hash, same as CEP-18
+fn get_immediate_caller() -> Key {
+    let call_stack = runtime::get_call_stack();
+    match call_stack.iter().nth_back(1) {
+        Some(CallStackElement::Session { account_hash }) => Key::Account(*account_hash),
+        Some(CallStackElement::StoredSession { account_hash, .. }) => Key::Account(*account_hash),
+        Some(CallStackElement::StoredContract {
+            contract_package_hash,
+            ..
+        }) => Key::from(*contract_package_hash),
+        None => runtime::revert(ApiError::Unhandled),
+    }
+}
+
+fn move_balance(owner: Key, recipient: Key, amount: U256) {
+    let owner_key = hashed_key(&[&owner]);
+    let owner_balance = read_amount(BALANCES, &owner_key);
+    if owner_balance < amount {
+        runtime::revert(ApiError::User(60001));
+    }
+    write_amount(BALANCES, &owner_key, owner_balance - amount);
+
+    let recipient_key = hashed_key(&[&recipient]);
+    let recipient_balance = read_amount(BALANCES, &recipient_key);
+    write_amount(BALANCES, &recipient_key, recipient_balance + amount);
+}
+
+#[no_mangle]
+pub extern "C" fn transfer() {
+    let recipient: Key = runtime::get_named_arg("recipient");
+    let amount: U256 = runtime::get_named_arg("amount");
+
+    move_balance(get_immediate_caller(), recipient, amount);
+}
+
+#[no_mangle]
+pub extern "C" fn approve() {
+    let spender: Key = runtime::get_named_arg("spender");
+    let amount: U256 = runtime::get_named_arg("amount");
+
+    let key = hashed_key(&[&get_immediate_caller(), &spender]);
+    write_amount(ALLOWANCES, &key, amount);
+}
+
+#[no_mangle]
+pub extern "C" fn transfer_from() {
+    let owner: Key = runtime::get_named_arg("owner");
+    let recipient: Key = runtime::get_named_arg("recipient");
+    let amount: U256 = runtime::get_named_arg("amount");
+
+    let key = hashed_key(&[&owner, &get_immediate_caller()]);
+    let allowance = read_amount(ALLOWANCES, &key);
+    if allowance < amount {
+        runtime::revert(ApiError::User(60002));
+    }
+    write_amount(ALLOWANCES, &key, allowance - amount);
+
+    move_balance(owner, recipient, amount);
+}
+
+#[no_mangle]
+pub extern "C" fn balance_of() {
+    let address: Key = runtime::get_named_arg("address");
+
+    let balance = read_amount(BALANCES, &hashed_key(&[&address]));
+    runtime::ret(CLValue::from_t(balance).unwrap_or_revert())
+}
+
+fn get_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "transfer",
+        vec![
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new("amount", U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "approve",
+        vec![
+            Parameter::new("spender", Key::cl_type()),
+            Parameter::new("amount", U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "transfer_from",
+        vec![
+            Parameter::new("owner", Key::cl_type()),
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new("amount", U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "balance_of",
+        vec![Parameter::new("address", Key::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let total_supply: U256 = runtime::get_named_arg("total_supply");
+
+    // Whole supply goes to installer
+    let balances = storage::new_dictionary("cep18_balances").unwrap_or_revert();
+    let allowances = storage::new_dictionary("cep18_allowances").unwrap_or_revert();
+    let installer = Key::Account(runtime::get_caller());
+    storage::dictionary_put(balances, &hashed_key(&[&installer]), total_supply);
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(String::from(BALANCES), balances.into());
+    named_keys.insert(String::from(ALLOWANCES), allowances.into());
+
+    let (contract_hash, _version) = storage::new_contract(
+        get_entry_points(),
+        Some(named_keys),
+        Some(String::from("cep18_package_hash")),
+        None,
+    );
+    runtime::put_key("cep18_contract_hash", contract_hash.into());
+}
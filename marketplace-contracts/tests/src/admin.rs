@@ -0,0 +1,111 @@
+use casper_types::{U256, U512};
+
+use crate::helpers::{
+    blockchain_helpers::{get_pending_balance, get_user},
+    constants::MARKETPLACE_FEE_PERCENTAGE,
+    fixtures::{get_default_fixture, get_listing_created_fixture},
+    marketplace_actions::{
+        build_accept_admin_request, build_cancel_admin_proposal_request,
+        build_propose_admin_request, build_set_marketplace_fee_request, create_buy_nft_request,
+    },
+};
+
+#[test]
+fn should_transfer_admin_only_after_acceptance() {
+    let (mut builder, market_hash, _cep47_hash, _cep78_hash, _market_package_hash) =
+        get_default_fixture();
+
+    let installer = get_user(&mut builder, 0);
+    let new_admin = get_user(&mut builder, 1);
+    let other_user = get_user(&mut builder, 2);
+
+    // Only current admin can propose
+    let req = build_propose_admin_request(new_admin, market_hash, new_admin);
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_propose_admin_request(installer, market_hash, new_admin);
+    builder.exec(req).expect_success().commit();
+
+    // Proposal alone doesn't hand over rights
+    let req = build_set_marketplace_fee_request(new_admin, market_hash, 0);
+    builder.exec(req).expect_failure().commit();
+
+    // Only proposed account can accept
+    let req = build_accept_admin_request(other_user, market_hash);
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_accept_admin_request(new_admin, market_hash);
+    builder.exec(req).expect_success().commit();
+
+    let req = build_set_marketplace_fee_request(new_admin, market_hash, 0);
+    builder.exec(req).expect_success().commit();
+
+    let req = build_set_marketplace_fee_request(installer, market_hash, 0);
+    builder.exec(req).expect_failure().commit();
+}
+
+#[test]
+fn should_let_cancel_admin_proposal() {
+    let (mut builder, market_hash, _cep47_hash, _cep78_hash, _market_package_hash) =
+        get_default_fixture();
+
+    let installer = get_user(&mut builder, 0);
+    let new_admin = get_user(&mut builder, 1);
+
+    // Nothing to cancel yet
+    let req = build_cancel_admin_proposal_request(installer, market_hash);
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_propose_admin_request(installer, market_hash, new_admin);
+    builder.exec(req).expect_success().commit();
+
+    let req = build_cancel_admin_proposal_request(new_admin, market_hash);
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_cancel_admin_proposal_request(installer, market_hash);
+    builder.exec(req).expect_success().commit();
+
+    let req = build_accept_admin_request(new_admin, market_hash);
+    builder.exec(req).expect_failure().commit();
+}
+
+#[test]
+fn should_pay_fees_to_new_admin() {
+    let (
+        mut builder,
+        marketplace_hash,
+        cep47_hash,
+        _cep78_hash,
+        _marketplace_package_hash,
+        listing_price,
+        _listing_duration_minutes,
+    ) = get_listing_created_fixture();
+
+    let installer = get_user(&mut builder, 0);
+    let new_admin = get_user(&mut builder, 3);
+
+    let req = build_propose_admin_request(installer, marketplace_hash, new_admin);
+    builder.exec(req).expect_success().commit();
+
+    let req = build_accept_admin_request(new_admin, marketplace_hash);
+    builder.exec(req).expect_success().commit();
+
+    let balance_admin_before = get_pending_balance(&mut builder, marketplace_hash, new_admin);
+
+    // For CEP47
+    let req = create_buy_nft_request(
+        get_user(&mut builder, 1),
+        U256::from(1),
+        marketplace_hash,
+        cep47_hash,
+        listing_price.as_u64(),
+        0,
+        0,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let balance_admin_after = get_pending_balance(&mut builder, marketplace_hash, new_admin);
+
+    let expected_fee = listing_price * U512::from(MARKETPLACE_FEE_PERCENTAGE) / U512::from(100);
+    assert_eq!(expected_fee, balance_admin_after - balance_admin_before);
+}
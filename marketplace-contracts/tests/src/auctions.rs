@@ -0,0 +1,1019 @@
+use casper_types::{runtime_args, RuntimeArgs, U256, U512};
+
+use crate::helpers::{
+    blockchain_helpers::{
+        get_account_balance, get_pending_balance, get_reserve_commitment, get_user,
+    },
+    cep47_helpers::approve_cep_47,
+    cep78_helpers::approve_cep_78,
+    constants::MARKETPLACE_FEE_PERCENTAGE,
+    fixtures::get_default_fixture,
+    marketplace_actions::{
+        build_cancel_dutch_auction_request, build_end_auction_request,
+        build_end_auction_with_reveal_request, build_get_current_price_request,
+        build_set_auction_extension_request, build_start_auction_with_args_request,
+        build_start_dutch_auction_request, create_buy_dutch_auction_request,
+        create_buy_now_request, create_place_bid_request,
+        create_place_bid_with_purse_amount_request,
+    },
+};
+
+#[test]
+fn should_sell_dutch_auction_at_linearly_decayed_price() {
+    let (mut builder, marketplace_hash, cep47_hash, _cep78_hash, marketplace_package_hash) =
+        get_default_fixture();
+
+    let buyer = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let start_price = U512::from(1_000_000_000_000u64);
+    let floor_price = U512::from(200_000_000_000u64);
+    let start_time = 1_000;
+
+    approve_cep_47(
+        &mut builder,
+        seller,
+        cep47_hash,
+        marketplace_package_hash.into(),
+        vec![U256::from(1)],
+    );
+
+    // Price can't rise over time
+    let req = build_start_dutch_auction_request(
+        seller,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        0,
+        floor_price,
+        start_price,
+        10,
+        0,
+        start_time,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    // Free floor would give token away at end
+    let req = build_start_dutch_auction_request(
+        seller,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        0,
+        start_price,
+        U512::zero(),
+        10,
+        0,
+        start_time,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_start_dutch_auction_request(
+        seller,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        0,
+        start_price,
+        floor_price,
+        10,
+        0,
+        start_time,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let req = build_get_current_price_request(
+        buyer,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        start_time + 300_000,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Halfway through price is halfway between start and floor price
+    let current_price = U512::from(600_000_000_000u64);
+    let req = create_buy_dutch_auction_request(
+        buyer,
+        U256::from(1),
+        current_price - 1,
+        marketplace_hash,
+        cep47_hash,
+        start_time + 300_000,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    // Paying more than current price only costs current price
+    let req = create_buy_dutch_auction_request(
+        buyer,
+        U256::from(1),
+        start_price,
+        marketplace_hash,
+        cep47_hash,
+        start_time + 300_000,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let expected_fee = current_price * MARKETPLACE_FEE_PERCENTAGE / 100;
+    assert_eq!(
+        get_pending_balance(&mut builder, marketplace_hash, seller),
+        current_price - expected_fee
+    );
+
+    // First buyer wins, auction is closed
+    let req = create_buy_dutch_auction_request(
+        buyer,
+        U256::from(1),
+        start_price,
+        marketplace_hash,
+        cep47_hash,
+        start_time + 300_000,
+    );
+    builder.exec(req).expect_failure().commit();
+}
+
+#[test]
+fn should_drop_dutch_auction_price_stepwise_and_let_only_seller_cancel() {
+    let (mut builder, marketplace_hash, _cep47_hash, cep78_hash, _marketplace_package_hash) =
+        get_default_fixture();
+
+    let buyer = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let start_price = U512::from(1_000_000_000_000u64);
+    let floor_price = U512::from(200_000_000_000u64);
+    let start_time = 1_000;
+
+    approve_cep_78(&mut builder, seller, cep78_hash, marketplace_hash.into(), 0);
+
+    // Price drops every 5 minutes of 10 minute auction
+    let req = build_start_dutch_auction_request(
+        seller,
+        marketplace_hash,
+        cep78_hash,
+        U256::from(0),
+        1,
+        start_price,
+        floor_price,
+        10,
+        5,
+        start_time,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Price holds until first step passes
+    let req = create_buy_dutch_auction_request(
+        buyer,
+        U256::from(0),
+        start_price - 1,
+        marketplace_hash,
+        cep78_hash,
+        start_time + 240_000,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_cancel_dutch_auction_request(buyer, marketplace_hash, cep78_hash, U256::from(0));
+    builder.exec(req).expect_failure().commit();
+
+    let req =
+        build_cancel_dutch_auction_request(seller, marketplace_hash, cep78_hash, U256::from(0));
+    builder.exec(req).expect_success().commit();
+
+    let req = create_buy_dutch_auction_request(
+        buyer,
+        U256::from(0),
+        start_price,
+        marketplace_hash,
+        cep78_hash,
+        start_time + 240_000,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    // Token is back with seller, so it can be auctioned again
+    approve_cep_78(&mut builder, seller, cep78_hash, marketplace_hash.into(), 0);
+    let req = build_start_dutch_auction_request(
+        seller,
+        marketplace_hash,
+        cep78_hash,
+        U256::from(0),
+        1,
+        start_price,
+        floor_price,
+        10,
+        5,
+        start_time,
+    );
+    builder.exec(req).expect_success().commit();
+}
+
+#[test]
+fn should_refund_highest_bid_below_reserve_price() {
+    let (mut builder, marketplace_hash, cep47_hash, _cep78_hash, marketplace_package_hash) =
+        get_default_fixture();
+
+    let bidder = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let starting_price = U512::from(1_000_000_000_000u64);
+    let reserve_price = U512::from(2_000_000_000_000u64);
+    let start_time = 1_000;
+    let end_time = start_time + 20 * 60_000;
+
+    approve_cep_47(
+        &mut builder,
+        seller,
+        cep47_hash,
+        marketplace_package_hash.into(),
+        vec![U256::from(1)],
+    );
+
+    // Reserve is committed to by hash, anything else can't be revealed later
+    let req = build_start_auction_with_args_request(
+        seller,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        0,
+        starting_price,
+        runtime_args! { "reserve_commitment" => "2000000000000".to_string() },
+        start_time,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_start_auction_with_args_request(
+        seller,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        0,
+        starting_price,
+        runtime_args! {
+            "reserve_commitment" => get_reserve_commitment(reserve_price, "salt"),
+            "min_increment_basis_points" => 1000u64
+        },
+        start_time,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Bid must raise current bid by 10%
+    let req = create_place_bid_request(
+        bidder,
+        U256::from(1),
+        U512::from(1_050_000_000_000u64),
+        marketplace_hash,
+        cep47_hash,
+        start_time + 1_000,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let bid = U512::from(1_100_000_000_000u64);
+    let req = create_place_bid_request(
+        bidder,
+        U256::from(1),
+        bid,
+        marketplace_hash,
+        cep47_hash,
+        start_time + 1_000,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Auction with bids waits for seller to reveal reserve
+    let req = build_end_auction_request(
+        seller,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        end_time + 1_000,
+        0,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    // Reveal must match commitment
+    let req = build_end_auction_with_reveal_request(
+        seller,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        U512::from(1_000_000_000_001u64),
+        "salt",
+        end_time + 1_000,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_end_auction_with_reveal_request(
+        seller,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        reserve_price,
+        "salt",
+        end_time + 1_000,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Bid is refunded and seller gets nothing
+    assert_eq!(
+        get_pending_balance(&mut builder, marketplace_hash, bidder),
+        bid
+    );
+    assert_eq!(
+        get_pending_balance(&mut builder, marketplace_hash, seller),
+        U512::zero()
+    );
+
+    // Token is back with seller, so it can be auctioned again
+    approve_cep_47(
+        &mut builder,
+        seller,
+        cep47_hash,
+        marketplace_package_hash.into(),
+        vec![U256::from(1)],
+    );
+    let req = build_start_auction_with_args_request(
+        seller,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        0,
+        starting_price,
+        RuntimeArgs::new(),
+        end_time + 2_000,
+    );
+    builder.exec(req).expect_success().commit();
+}
+
+#[test]
+fn should_refund_bid_purse_above_bid_amount() {
+    let (mut builder, marketplace_hash, cep47_hash, _cep78_hash, marketplace_package_hash) =
+        get_default_fixture();
+
+    let bidder = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let starting_price = U512::from(1_000_000_000_000u64);
+    let start_time = 1_000;
+
+    approve_cep_47(
+        &mut builder,
+        seller,
+        cep47_hash,
+        marketplace_package_hash.into(),
+        vec![U256::from(1)],
+    );
+
+    let req = build_start_auction_with_args_request(
+        seller,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        0,
+        starting_price,
+        RuntimeArgs::new(),
+        start_time,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let balance_before = get_account_balance(&mut builder, bidder);
+
+    let bid = U512::from(1_100_000_000_000u64);
+    let req = create_place_bid_with_purse_amount_request(
+        bidder,
+        U256::from(1),
+        bid,
+        bid + U512::from(500_000_000_000u64),
+        marketplace_hash,
+        cep47_hash,
+        start_time + 1_000,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Only bid stays locked, rest of purse is back with bidder
+    assert_eq!(balance_before - get_account_balance(&mut builder, bidder), bid);
+    assert_eq!(
+        get_pending_balance(&mut builder, marketplace_hash, bidder),
+        U512::zero()
+    );
+}
+
+#[test]
+fn should_sell_auction_when_reserve_is_not_revealed() {
+    let (mut builder, marketplace_hash, cep47_hash, _cep78_hash, marketplace_package_hash) =
+        get_default_fixture();
+
+    let bidder = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let starting_price = U512::from(1_000_000_000_000u64);
+    let reserve_price = U512::from(2_000_000_000_000u64);
+    let start_time = 1_000;
+    let end_time = start_time + 20 * 60_000;
+    let reveal_end_time = end_time + 1_440 * 60_000;
+
+    approve_cep_47(
+        &mut builder,
+        seller,
+        cep47_hash,
+        marketplace_package_hash.into(),
+        vec![U256::from(1)],
+    );
+    let req = build_start_auction_with_args_request(
+        seller,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        0,
+        starting_price,
+        runtime_args! { "reserve_commitment" => get_reserve_commitment(reserve_price, "salt") },
+        start_time,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let bid = U512::from(1_100_000_000_000u64);
+    let req = create_place_bid_request(
+        bidder,
+        U256::from(1),
+        bid,
+        marketplace_hash,
+        cep47_hash,
+        start_time + 1_000,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Bidder can't end auction before seller had time to reveal
+    let req = build_end_auction_request(
+        bidder,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        reveal_end_time - 1,
+        0,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    // Unrevealed reserve counts as met, so seller can't hold bidder hostage
+    let req = build_end_auction_request(
+        bidder,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        reveal_end_time,
+        0,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let expected_fee = bid * MARKETPLACE_FEE_PERCENTAGE / 100;
+    assert_eq!(
+        get_pending_balance(&mut builder, marketplace_hash, bidder),
+        U512::zero()
+    );
+    assert_eq!(
+        get_pending_balance(&mut builder, marketplace_hash, seller),
+        bid - expected_fee
+    );
+}
+
+#[test]
+fn should_sell_auction_once_bids_meet_increment_and_reserve() {
+    let (mut builder, marketplace_hash, _cep47_hash, cep78_hash, _marketplace_package_hash) =
+        get_default_fixture();
+
+    let installer = get_user(&mut builder, 0);
+    let bidder = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let starting_price = U512::from(1_000_000_000_000u64);
+    let reserve_price = U512::from(1_500_000_000_000u64);
+    let start_time = 1_000;
+    let end_time = start_time + 20 * 60_000;
+
+    approve_cep_78(&mut builder, seller, cep78_hash, marketplace_hash.into(), 0);
+
+    let req = build_start_auction_with_args_request(
+        seller,
+        marketplace_hash,
+        cep78_hash,
+        U256::from(0),
+        1,
+        starting_price,
+        runtime_args! {
+            "reserve_commitment" => get_reserve_commitment(reserve_price, "salt"),
+            "min_increment" => U512::from(300_000_000_000u64)
+        },
+        start_time,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Absolute increment applies to first bid too
+    let req = create_place_bid_request(
+        bidder,
+        U256::from(0),
+        U512::from(1_299_000_000_000u64),
+        marketplace_hash,
+        cep78_hash,
+        start_time + 1_000,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let first_bid = U512::from(1_300_000_000_000u64);
+    let req = create_place_bid_request(
+        bidder,
+        U256::from(0),
+        first_bid,
+        marketplace_hash,
+        cep78_hash,
+        start_time + 1_000,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let req = create_place_bid_request(
+        installer,
+        U256::from(0),
+        U512::from(1_500_000_000_000u64),
+        marketplace_hash,
+        cep78_hash,
+        start_time + 2_000,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let winning_bid = U512::from(1_600_000_000_000u64);
+    let req = create_place_bid_request(
+        installer,
+        U256::from(0),
+        winning_bid,
+        marketplace_hash,
+        cep78_hash,
+        start_time + 2_000,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let req = build_end_auction_with_reveal_request(
+        seller,
+        marketplace_hash,
+        cep78_hash,
+        U256::from(0),
+        reserve_price,
+        "salt",
+        end_time + 1_000,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Outbid bidder is refunded, seller is paid winning bid minus fee
+    let expected_fee = winning_bid * MARKETPLACE_FEE_PERCENTAGE / 100;
+    assert_eq!(
+        get_pending_balance(&mut builder, marketplace_hash, bidder),
+        first_bid
+    );
+    assert_eq!(
+        get_pending_balance(&mut builder, marketplace_hash, seller),
+        winning_bid - expected_fee
+    );
+
+    // Auction is closed
+    let req = build_end_auction_request(
+        seller,
+        marketplace_hash,
+        cep78_hash,
+        U256::from(0),
+        end_time + 2_000,
+        1,
+    );
+    builder.exec(req).expect_failure().commit();
+}
+
+#[test]
+fn should_extend_auction_up_to_default_extension_cap() {
+    let (mut builder, marketplace_hash, cep47_hash, _cep78_hash, marketplace_package_hash) =
+        get_default_fixture();
+
+    let installer = get_user(&mut builder, 0);
+    let bidder = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let start_time = 1_000;
+    let end_time = start_time + 20 * 60_000;
+
+    // Only admins can change default
+    let req = build_set_auction_extension_request(bidder, marketplace_hash, 5, 5, 1);
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_set_auction_extension_request(installer, marketplace_hash, 5, 5, 1);
+    builder.exec(req).expect_success().commit();
+
+    approve_cep_47(
+        &mut builder,
+        seller,
+        cep47_hash,
+        marketplace_package_hash.into(),
+        vec![U256::from(1)],
+    );
+
+    let req = build_start_auction_with_args_request(
+        seller,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        0,
+        U512::from(1_000_000_000_000u64),
+        RuntimeArgs::new(),
+        start_time,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Bid a minute before end moves end 5 minutes past the bid
+    let req = create_place_bid_request(
+        bidder,
+        U256::from(1),
+        U512::from(1_100_000_000_000u64),
+        marketplace_hash,
+        cep47_hash,
+        end_time - 60_000,
+    );
+    builder.exec(req).expect_success().commit();
+    let extended_end_time = end_time + 4 * 60_000;
+
+    // Cap is reached, so this bid keeps end time
+    let req = create_place_bid_request(
+        installer,
+        U256::from(1),
+        U512::from(1_200_000_000_000u64),
+        marketplace_hash,
+        cep47_hash,
+        extended_end_time - 60_000,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let req = build_end_auction_request(
+        seller,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        end_time + 1_000,
+        0,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let req = create_place_bid_request(
+        bidder,
+        U256::from(1),
+        U512::from(1_300_000_000_000u64),
+        marketplace_hash,
+        cep47_hash,
+        extended_end_time + 1_000,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_end_auction_request(
+        seller,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        extended_end_time + 1_000,
+        0,
+    );
+    builder.exec(req).expect_success().commit();
+}
+
+#[test]
+fn should_not_extend_auction_without_extension_window() {
+    let (mut builder, marketplace_hash, _cep47_hash, cep78_hash, _marketplace_package_hash) =
+        get_default_fixture();
+
+    let bidder = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let start_time = 1_000;
+    let end_time = start_time + 20 * 60_000;
+
+    approve_cep_78(&mut builder, seller, cep78_hash, marketplace_hash.into(), 0);
+
+    // Auction settings override marketplace default
+    let req = build_start_auction_with_args_request(
+        seller,
+        marketplace_hash,
+        cep78_hash,
+        U256::from(0),
+        1,
+        U512::from(1_000_000_000_000u64),
+        runtime_args! { "extension_window_minutes" => 0u64 },
+        start_time,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let req = create_place_bid_request(
+        bidder,
+        U256::from(0),
+        U512::from(1_100_000_000_000u64),
+        marketplace_hash,
+        cep78_hash,
+        end_time - 60_000,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let req = build_end_auction_request(
+        seller,
+        marketplace_hash,
+        cep78_hash,
+        U256::from(0),
+        end_time + 1_000,
+        1,
+    );
+    builder.exec(req).expect_success().commit();
+}
+
+#[test]
+fn should_only_count_extensions_that_move_auction_end() {
+    let (mut builder, marketplace_hash, cep47_hash, _cep78_hash, marketplace_package_hash) =
+        get_default_fixture();
+
+    let installer = get_user(&mut builder, 0);
+    let bidder = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let start_time = 1_000;
+    let end_time = start_time + 20 * 60_000;
+
+    let req = build_set_auction_extension_request(installer, marketplace_hash, 5, 1, 1);
+    builder.exec(req).expect_success().commit();
+
+    approve_cep_47(
+        &mut builder,
+        seller,
+        cep47_hash,
+        marketplace_package_hash.into(),
+        vec![U256::from(1)],
+    );
+
+    let req = build_start_auction_with_args_request(
+        seller,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        0,
+        U512::from(1_000_000_000_000u64),
+        RuntimeArgs::new(),
+        start_time,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Bid inside window but more than a minute before end keeps end and extension
+    let req = create_place_bid_request(
+        bidder,
+        U256::from(1),
+        U512::from(1_100_000_000_000u64),
+        marketplace_hash,
+        cep47_hash,
+        end_time - 3 * 60_000,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Bid 30 seconds before end uses the only extension
+    let req = create_place_bid_request(
+        installer,
+        U256::from(1),
+        U512::from(1_200_000_000_000u64),
+        marketplace_hash,
+        cep47_hash,
+        end_time - 30_000,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let req = build_end_auction_request(
+        seller,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        end_time + 1_000,
+        0,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let req = create_place_bid_request(
+        bidder,
+        U256::from(1),
+        U512::from(1_300_000_000_000u64),
+        marketplace_hash,
+        cep47_hash,
+        end_time + 10_000,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let req = build_end_auction_request(
+        seller,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        end_time + 31_000,
+        0,
+    );
+    builder.exec(req).expect_success().commit();
+}
+
+#[test]
+fn should_reject_invalid_extension_settings() {
+    let (mut builder, marketplace_hash, cep47_hash, _cep78_hash, marketplace_package_hash) =
+        get_default_fixture();
+
+    let installer = get_user(&mut builder, 0);
+    let seller = get_user(&mut builder, 2);
+
+    // Enabled extensions need a length
+    let req = build_set_auction_extension_request(installer, marketplace_hash, 5, 0, 1);
+    builder.exec(req).expect_failure().commit();
+
+    // Window and length are capped at a day
+    let req = build_set_auction_extension_request(installer, marketplace_hash, 1_441, 5, 1);
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_set_auction_extension_request(installer, marketplace_hash, 5, 1_441, 1);
+    builder.exec(req).expect_failure().commit();
+
+    approve_cep_47(
+        &mut builder,
+        seller,
+        cep47_hash,
+        marketplace_package_hash.into(),
+        vec![U256::from(1)],
+    );
+
+    let req = build_start_auction_with_args_request(
+        seller,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        0,
+        U512::from(1_000_000_000_000u64),
+        runtime_args! { "extension_minutes" => u64::MAX / 60_000 },
+        1_000,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_start_auction_with_args_request(
+        seller,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        0,
+        U512::from(1_000_000_000_000u64),
+        runtime_args! { "extension_minutes" => 0u64 },
+        1_000,
+    );
+    builder.exec(req).expect_failure().commit();
+}
+
+#[test]
+fn should_end_auction_with_buy_now_and_refund_highest_bidder() {
+    let (mut builder, marketplace_hash, cep47_hash, _cep78_hash, marketplace_package_hash) =
+        get_default_fixture();
+
+    let installer = get_user(&mut builder, 0);
+    let bidder = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let starting_price = U512::from(1_000_000_000_000u64);
+    let buy_now_price = U512::from(2_000_000_000_000u64);
+    let start_time = 1_000;
+
+    approve_cep_47(
+        &mut builder,
+        seller,
+        cep47_hash,
+        marketplace_package_hash.into(),
+        vec![U256::from(1)],
+    );
+
+    // Buy now must beat starting price
+    let req = build_start_auction_with_args_request(
+        seller,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        0,
+        starting_price,
+        runtime_args! { "buy_now_price" => starting_price },
+        start_time,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_start_auction_with_args_request(
+        seller,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        0,
+        starting_price,
+        runtime_args! { "buy_now_price" => buy_now_price },
+        start_time,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let bid = U512::from(1_100_000_000_000u64);
+    let req = create_place_bid_request(
+        bidder,
+        U256::from(1),
+        bid,
+        marketplace_hash,
+        cep47_hash,
+        start_time + 1_000,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Amount paid must match buy now price
+    let req = create_buy_now_request(
+        installer,
+        U256::from(1),
+        buy_now_price - 1,
+        marketplace_hash,
+        cep47_hash,
+        start_time + 2_000,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let req = create_buy_now_request(
+        installer,
+        U256::from(1),
+        buy_now_price,
+        marketplace_hash,
+        cep47_hash,
+        start_time + 2_000,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Highest bid is refunded, seller is paid buy now price minus fee
+    let expected_fee = buy_now_price * MARKETPLACE_FEE_PERCENTAGE / 100;
+    assert_eq!(
+        get_pending_balance(&mut builder, marketplace_hash, bidder),
+        bid
+    );
+    assert_eq!(
+        get_pending_balance(&mut builder, marketplace_hash, seller),
+        buy_now_price - expected_fee
+    );
+
+    // Auction is closed
+    let req = create_place_bid_request(
+        bidder,
+        U256::from(1),
+        U512::from(1_200_000_000_000u64),
+        marketplace_hash,
+        cep47_hash,
+        start_time + 3_000,
+    );
+    builder.exec(req).expect_failure().commit();
+}
+
+#[test]
+fn should_not_accept_bids_before_scheduled_auction_start() {
+    let (mut builder, marketplace_hash, _cep47_hash, cep78_hash, _marketplace_package_hash) =
+        get_default_fixture();
+
+    let bidder = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let bid = U512::from(1_100_000_000_000u64);
+    let start_time = 600_000;
+    let end_time = start_time + 20 * 60_000;
+
+    approve_cep_78(&mut builder, seller, cep78_hash, marketplace_hash.into(), 0);
+
+    let req = build_start_auction_with_args_request(
+        seller,
+        marketplace_hash,
+        cep78_hash,
+        U256::from(0),
+        1,
+        U512::from(1_000_000_000_000u64),
+        runtime_args! { "start_time" => start_time },
+        1_000,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let req = create_place_bid_request(
+        bidder,
+        U256::from(0),
+        bid,
+        marketplace_hash,
+        cep78_hash,
+        start_time - 60_000,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let req = create_place_bid_request(
+        bidder,
+        U256::from(0),
+        bid,
+        marketplace_hash,
+        cep78_hash,
+        start_time + 60_000,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Duration counts from start time
+    let req = build_end_auction_request(
+        seller,
+        marketplace_hash,
+        cep78_hash,
+        U256::from(0),
+        end_time - 60_000,
+        1,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_end_auction_request(
+        seller,
+        marketplace_hash,
+        cep78_hash,
+        U256::from(0),
+        end_time + 1_000,
+        1,
+    );
+    builder.exec(req).expect_success().commit();
+}
@@ -0,0 +1,190 @@
+use casper_types::{U256, U512};
+
+use crate::helpers::{
+    blockchain_helpers::{get_pending_balance, get_user},
+    cep47_helpers::approve_cep_47,
+    constants::ROLE_MODERATOR,
+    fixtures::{get_default_fixture, get_listing_created_fixture},
+    marketplace_actions::{
+        build_block_account_request, build_block_token_request, build_end_auction_request,
+        build_force_delist_request, build_grant_role_request,
+        build_start_auction_request, build_unblock_account_request, build_unblock_token_request,
+        create_buy_nft_request, create_listing, create_place_bid_request,
+    },
+};
+
+#[test]
+fn should_let_moderator_force_delist_blocked_token() {
+    let (
+        mut builder,
+        marketplace_hash,
+        cep47_hash,
+        _cep78_hash,
+        _marketplace_package_hash,
+        listing_price,
+        listing_duration_minutes,
+    ) = get_listing_created_fixture();
+
+    let installer = get_user(&mut builder, 0);
+    let buyer = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let moderator = get_user(&mut builder, 3);
+
+    // Only moderators can block tokens
+    let req = build_block_token_request(moderator, marketplace_hash, cep47_hash, U256::one());
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_grant_role_request(installer, marketplace_hash, ROLE_MODERATOR, moderator);
+    builder.exec(req).expect_success().commit();
+
+    // Listing of token that is not blocked can't be removed
+    let req = build_force_delist_request(moderator, marketplace_hash, cep47_hash, U256::one());
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_block_token_request(moderator, marketplace_hash, cep47_hash, U256::one());
+    builder.exec(req).expect_success().commit();
+
+    // Blocked token can't be bought
+    let req = create_buy_nft_request(
+        buyer,
+        U256::one(),
+        marketplace_hash,
+        cep47_hash,
+        listing_price.as_u64(),
+        0,
+        0,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_force_delist_request(moderator, marketplace_hash, cep47_hash, U256::one());
+    builder.exec(req).expect_success().commit();
+
+    // Listing is gone
+    let req = build_force_delist_request(moderator, marketplace_hash, cep47_hash, U256::one());
+    builder.exec(req).expect_failure().commit();
+
+    // Blocked token can't be listed again until unblocked
+    let req = create_listing(
+        seller,
+        marketplace_hash,
+        cep47_hash,
+        1,
+        listing_price,
+        listing_duration_minutes,
+        0,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_unblock_token_request(moderator, marketplace_hash, cep47_hash, U256::one());
+    builder.exec(req).expect_success().commit();
+
+    let req = create_listing(
+        seller,
+        marketplace_hash,
+        cep47_hash,
+        1,
+        listing_price,
+        listing_duration_minutes,
+        0,
+    );
+    builder.exec(req).expect_success().commit();
+}
+
+#[test]
+fn should_refuse_trades_with_blocked_accounts() {
+    let (
+        mut builder,
+        marketplace_hash,
+        _cep47_hash,
+        cep78_hash,
+        _marketplace_package_hash,
+        listing_price,
+        _listing_duration_minutes,
+    ) = get_listing_created_fixture();
+
+    let installer = get_user(&mut builder, 0);
+    let buyer = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+
+    // Only moderators can block accounts
+    let req = build_block_account_request(buyer, marketplace_hash, seller);
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_block_account_request(installer, marketplace_hash, seller);
+    builder.exec(req).expect_success().commit();
+
+    // Listings of blocked seller can't be bought
+    let req = create_buy_nft_request(
+        buyer,
+        U256::zero(),
+        marketplace_hash,
+        cep78_hash,
+        listing_price.as_u64(),
+        1,
+        0,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_unblock_account_request(installer, marketplace_hash, seller);
+    builder.exec(req).expect_success().commit();
+
+    let req = create_buy_nft_request(
+        buyer,
+        U256::zero(),
+        marketplace_hash,
+        cep78_hash,
+        listing_price.as_u64(),
+        1,
+        0,
+    );
+    builder.exec(req).expect_success().commit();
+}
+
+#[test]
+fn should_return_token_and_refund_bid_of_blocked_auction() {
+    let (mut builder, marketplace_hash, cep47_hash, _cep78_hash, marketplace_package_hash) =
+        get_default_fixture();
+
+    let installer = get_user(&mut builder, 0);
+    let bidder = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let bid = U512::from(1_000_000_000_000u64);
+
+    approve_cep_47(
+        &mut builder,
+        seller,
+        cep47_hash,
+        marketplace_package_hash.into(),
+        vec![U256::one()],
+    );
+    let req = build_start_auction_request(seller, marketplace_hash, cep47_hash, U256::one(), 0);
+    builder.exec(req).expect_success().commit();
+
+    let req = create_place_bid_request(bidder, U256::one(), bid, marketplace_hash, cep47_hash, 0);
+    builder.exec(req).expect_success().commit();
+
+    let req = build_block_token_request(installer, marketplace_hash, cep47_hash, U256::one());
+    builder.exec(req).expect_success().commit();
+
+    // Winning bid on blocked token is refunded instead of settled
+    let req =
+        build_end_auction_request(seller, marketplace_hash, cep47_hash, U256::one(), 1_300_000, 0);
+    builder.exec(req).expect_success().commit();
+
+    assert_eq!(get_pending_balance(&mut builder, marketplace_hash, bidder), bid);
+    assert_eq!(get_pending_balance(&mut builder, marketplace_hash, seller), U512::zero());
+
+    // Token is back with seller, who can list it once it is unblocked
+    let req = build_unblock_token_request(installer, marketplace_hash, cep47_hash, U256::one());
+    builder.exec(req).expect_success().commit();
+
+    approve_cep_47(
+        &mut builder,
+        seller,
+        cep47_hash,
+        marketplace_package_hash.into(),
+        vec![U256::one()],
+    );
+    let req = create_listing(seller, marketplace_hash, cep47_hash, 1, bid, 15, 0);
+    builder.exec(req).expect_success().commit();
+}
@@ -0,0 +1,197 @@
+use casper_types::{U256, U512};
+
+use crate::helpers::{
+    blockchain_helpers::{get_pending_balance, get_user},
+    cep47_helpers::approve_cep_47,
+    cep78_helpers::{
+        approve_cep_78, approve_cep_78_by_hash, deploy_cep_78_hash_mode, get_cep_78_token_hash,
+        mint_cep_78,
+    },
+    constants::MARKETPLACE_FEE_PERCENTAGE,
+    fixtures::{get_default_fixture, get_listing_created_fixture_with_royalties},
+    marketplace_actions::{
+        build_cancel_bundle_request, build_create_bundle_by_hash_request,
+        build_create_bundle_request, build_get_bundle_request,
+        create_buy_bundle_request,
+    },
+};
+
+#[test]
+fn should_sell_tokens_of_both_standards_as_bundle() {
+    let (
+        mut builder,
+        marketplace_hash,
+        cep47_hash,
+        cep78_hash,
+        _marketplace_package_hash,
+        _listing_price,
+        _listing_duration_minutes,
+        royalty_cep47,
+        royalty_cep78,
+        royalty_creator,
+    ) = get_listing_created_fixture_with_royalties();
+
+    let buyer = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let price = U512::from(2_000_000_000_000u64);
+
+    // Only owner of every token can bundle them
+    let req = build_create_bundle_request(
+        buyer,
+        marketplace_hash,
+        vec![cep47_hash, cep78_hash],
+        vec![U256::from(1), U256::from(0)],
+        price,
+        15,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_create_bundle_request(
+        seller,
+        marketplace_hash,
+        vec![cep47_hash, cep78_hash],
+        vec![U256::from(1), U256::from(0)],
+        price,
+        15,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let req = build_get_bundle_request(buyer, marketplace_hash, 1);
+    builder.exec(req).expect_success().commit();
+
+    // Amount must match bundle price
+    let req = create_buy_bundle_request(buyer, marketplace_hash, 1, price - 1);
+    builder.exec(req).expect_failure().commit();
+
+    let req = create_buy_bundle_request(buyer, marketplace_hash, 1, price);
+    builder.exec(req).expect_success().commit();
+
+    // Each half of price pays royalty of its own collection
+    let expected_fee = price * MARKETPLACE_FEE_PERCENTAGE / 100;
+    let expected_royalty = price / 2 * royalty_cep47 / 100 + price / 2 * royalty_cep78 / 100;
+    assert_eq!(
+        get_pending_balance(&mut builder, marketplace_hash, royalty_creator),
+        expected_royalty
+    );
+    assert_eq!(
+        get_pending_balance(&mut builder, marketplace_hash, seller),
+        price - expected_fee - expected_royalty
+    );
+
+    // Bundle is closed
+    let req = create_buy_bundle_request(buyer, marketplace_hash, 1, price);
+    builder.exec(req).expect_failure().commit();
+}
+
+#[test]
+fn should_check_approvals_and_let_only_seller_cancel_bundle() {
+    let (mut builder, marketplace_hash, cep47_hash, cep78_hash, marketplace_package_hash) =
+        get_default_fixture();
+
+    let buyer = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let price = U512::from(1_000_000_000_000u64);
+
+    approve_cep_47(
+        &mut builder,
+        seller,
+        cep47_hash,
+        marketplace_package_hash.into(),
+        vec![U256::from(1)],
+    );
+
+    // Every token must be approved
+    let req = build_create_bundle_request(
+        seller,
+        marketplace_hash,
+        vec![cep47_hash, cep78_hash],
+        vec![U256::from(1), U256::from(0)],
+        price,
+        0,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    // Empty bundle is rejected
+    let req = build_create_bundle_request(seller, marketplace_hash, vec![], vec![], price, 0);
+    builder.exec(req).expect_failure().commit();
+
+    // Same token can't be sold twice in one bundle
+    let req = build_create_bundle_request(
+        seller,
+        marketplace_hash,
+        vec![cep47_hash, cep47_hash],
+        vec![U256::from(1), U256::from(1)],
+        price,
+        0,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    approve_cep_78(&mut builder, seller, cep78_hash, marketplace_hash.into(), 0);
+
+    let req = build_create_bundle_request(
+        seller,
+        marketplace_hash,
+        vec![cep47_hash, cep78_hash],
+        vec![U256::from(1), U256::from(0)],
+        price,
+        0,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let req = build_cancel_bundle_request(buyer, marketplace_hash, 1);
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_cancel_bundle_request(seller, marketplace_hash, 1);
+    builder.exec(req).expect_success().commit();
+
+    let req = create_buy_bundle_request(buyer, marketplace_hash, 1, price);
+    builder.exec(req).expect_failure().commit();
+}
+
+#[test]
+fn should_sell_bundle_with_hash_identified_token() {
+    let (mut builder, marketplace_hash, cep47_hash, _cep78_hash, marketplace_package_hash) =
+        get_default_fixture();
+
+    let buyer = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let price = U512::from(1_000_000_000_000u64);
+
+    let cep78_hash = deploy_cep_78_hash_mode(&mut builder);
+    mint_cep_78(&mut builder, cep78_hash, seller.into(), vec![]);
+    let token_hash = get_cep_78_token_hash("{\"name\":\"essa\"}");
+
+    approve_cep_47(
+        &mut builder,
+        seller,
+        cep47_hash,
+        marketplace_package_hash.into(),
+        vec![U256::from(1)],
+    );
+    approve_cep_78_by_hash(
+        &mut builder,
+        seller,
+        cep78_hash,
+        marketplace_hash.into(),
+        token_hash.clone(),
+    );
+
+    let req = build_create_bundle_by_hash_request(
+        seller,
+        marketplace_hash,
+        vec![cep47_hash, cep78_hash],
+        vec![U256::from(1), U256::zero()],
+        vec![None, Some(token_hash)],
+        price,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let req = create_buy_bundle_request(buyer, marketplace_hash, 1, price);
+    builder.exec(req).expect_success().commit();
+
+    let expected_fee = price * MARKETPLACE_FEE_PERCENTAGE / 100;
+    assert_eq!(
+        get_pending_balance(&mut builder, marketplace_hash, seller),
+        price - expected_fee
+    );
+}
@@ -0,0 +1,327 @@
+use casper_types::{Key, U256, U512};
+
+use crate::helpers::{
+    blockchain_helpers::{get_account_balance, get_pending_balance, get_user},
+    cep18_helpers::{approve_cep_18, deploy_cep_18, get_cep_18_balance, transfer_cep_18},
+    cep47_helpers::approve_cep_47,
+    cep78_helpers::approve_cep_78,
+    constants::MARKETPLACE_FEE_PERCENTAGE,
+    fixtures::get_default_fixture,
+    marketplace_actions::{
+        build_accept_offer_request, build_buy_listing_request, build_make_offer_request,
+        build_withdraw_token_request, create_buy_nft_request, create_buy_nft_with_cep18_request,
+        create_listing_with_payment_token, create_make_offer_with_payment_token_request,
+    },
+};
+
+#[test]
+fn should_buy_listing_priced_in_cep18_token() {
+    let (mut builder, marketplace_hash, cep47_hash, _cep78_hash, marketplace_package_hash) =
+        get_default_fixture();
+
+    let installer = get_user(&mut builder, 0);
+    let buyer = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let price = U512::from(1_000_000u64);
+
+    let cep18_hash = deploy_cep_18(&mut builder);
+    transfer_cep_18(&mut builder, installer, cep18_hash, buyer.into(), U256::from(1_000_000u64));
+
+    approve_cep_47(
+        &mut builder,
+        seller.into(),
+        cep47_hash,
+        marketplace_package_hash.into(),
+        vec![U256::from(1)],
+    );
+    let req = create_listing_with_payment_token(
+        seller,
+        marketplace_hash,
+        cep47_hash,
+        1,
+        price,
+        cep18_hash,
+        0,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Marketplace needs allowance to take payment
+    let req = build_buy_listing_request(
+        buyer,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        price,
+        cep18_hash,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    approve_cep_18(
+        &mut builder,
+        buyer,
+        cep18_hash,
+        Key::Hash(marketplace_package_hash.value()),
+        U256::from(1_000_000u64),
+    );
+    let installer_balance_before = get_cep_18_balance(&mut builder, cep18_hash, installer.into());
+
+    // Buyer paying same amount natively can't be charged in listing's token
+    let req = create_buy_nft_request(
+        buyer,
+        U256::from(1),
+        marketplace_hash,
+        cep47_hash,
+        price.as_u64(),
+        0,
+        0,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_buy_listing_request(
+        buyer,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        price,
+        cep18_hash,
+    );
+    builder.exec(req).expect_success().commit();
+
+    assert_eq!(get_cep_18_balance(&mut builder, cep18_hash, buyer.into()), U256::zero());
+
+    // Proceeds are credited, seller and fee recipient withdraw them
+    assert_eq!(get_cep_18_balance(&mut builder, cep18_hash, seller.into()), U256::zero());
+    let req = build_withdraw_token_request(seller, marketplace_hash, cep18_hash);
+    builder.exec(req).expect_success().commit();
+    let req = build_withdraw_token_request(installer, marketplace_hash, cep18_hash);
+    builder.exec(req).expect_success().commit();
+
+    assert_eq!(
+        get_cep_18_balance(&mut builder, cep18_hash, seller.into()),
+        U256::from(1_000_000u64 * (100 - MARKETPLACE_FEE_PERCENTAGE) / 100)
+    );
+    assert_eq!(
+        get_cep_18_balance(&mut builder, cep18_hash, installer.into()) - installer_balance_before,
+        U256::from(1_000_000u64 * MARKETPLACE_FEE_PERCENTAGE / 100)
+    );
+}
+
+#[test]
+fn should_accept_offer_made_in_cep18_token() {
+    let (mut builder, marketplace_hash, cep47_hash, _cep78_hash, marketplace_package_hash) =
+        get_default_fixture();
+
+    let installer = get_user(&mut builder, 0);
+    let offerer = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let amount = U512::from(500_000u64);
+
+    let cep18_hash = deploy_cep_18(&mut builder);
+    transfer_cep_18(&mut builder, installer, cep18_hash, offerer.into(), U256::from(500_000u64));
+    approve_cep_18(
+        &mut builder,
+        offerer,
+        cep18_hash,
+        Key::Hash(marketplace_package_hash.value()),
+        U256::from(500_000u64),
+    );
+
+    // Offer amount is held by marketplace
+    let req = build_make_offer_request(
+        offerer,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        amount,
+        cep18_hash,
+    );
+    builder.exec(req).expect_success().commit();
+    assert_eq!(
+        get_cep_18_balance(&mut builder, cep18_hash, Key::Hash(marketplace_package_hash.value())),
+        U256::from(500_000u64)
+    );
+
+    approve_cep_47(
+        &mut builder,
+        seller.into(),
+        cep47_hash,
+        marketplace_package_hash.into(),
+        vec![U256::from(1)],
+    );
+    // Seller may name amount and currency accepted, offer may have been replaced since
+    let req = build_accept_offer_request(
+        seller,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        offerer,
+        Some(amount),
+        None,
+        0,
+        0,
+    );
+    builder.exec(req).expect_failure().commit();
+    let req = build_accept_offer_request(
+        seller,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        offerer,
+        Some(amount + 1),
+        Some(cep18_hash),
+        0,
+        0,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_accept_offer_request(
+        seller,
+        marketplace_hash,
+        cep47_hash,
+        U256::from(1),
+        offerer,
+        Some(amount),
+        Some(cep18_hash),
+        0,
+        0,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let req = build_withdraw_token_request(seller, marketplace_hash, cep18_hash);
+    builder.exec(req).expect_success().commit();
+
+    // Fee stays held by marketplace until fee recipient withdraws it
+    assert_eq!(
+        get_cep_18_balance(&mut builder, cep18_hash, seller.into()),
+        U256::from(500_000u64 * (100 - MARKETPLACE_FEE_PERCENTAGE) / 100)
+    );
+    assert_eq!(
+        get_cep_18_balance(&mut builder, cep18_hash, Key::Hash(marketplace_package_hash.value())),
+        U256::from(500_000u64 * MARKETPLACE_FEE_PERCENTAGE / 100)
+    );
+
+    let req = build_withdraw_token_request(installer, marketplace_hash, cep18_hash);
+    builder.exec(req).expect_success().commit();
+    assert_eq!(
+        get_cep_18_balance(&mut builder, cep18_hash, Key::Hash(marketplace_package_hash.value())),
+        U256::zero()
+    );
+}
+
+#[test]
+fn should_trade_cep18_token_through_session_wasm() {
+    let (mut builder, marketplace_hash, cep47_hash, cep78_hash, marketplace_package_hash) =
+        get_default_fixture();
+
+    let installer = get_user(&mut builder, 0);
+    let buyer = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    // Token amount far above any gas cost, stranded motes would show in native balance
+    let price = U512::from(100_000_000_000_000u64);
+    let supplied = U256::from(300_000_000_000_000u64);
+
+    let cep18_hash = deploy_cep_18(&mut builder);
+    transfer_cep_18(&mut builder, installer, cep18_hash, buyer.into(), supplied);
+    approve_cep_18(
+        &mut builder,
+        buyer,
+        cep18_hash,
+        Key::Hash(marketplace_package_hash.value()),
+        supplied,
+    );
+
+    approve_cep_47(
+        &mut builder,
+        seller.into(),
+        cep47_hash,
+        marketplace_package_hash.into(),
+        vec![U256::from(1)],
+    );
+    let req = create_listing_with_payment_token(
+        seller,
+        marketplace_hash,
+        cep47_hash,
+        1,
+        price,
+        cep18_hash,
+        0,
+    );
+    builder.exec(req).expect_success().commit();
+
+    approve_cep_78(&mut builder, seller, cep78_hash, marketplace_hash.into(), 0);
+    let req = create_listing_with_payment_token(
+        seller,
+        marketplace_hash,
+        cep78_hash,
+        0,
+        price,
+        cep18_hash,
+        1,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Session must name the token listing is priced in
+    let req = create_buy_nft_with_cep18_request(
+        buyer,
+        U256::from(1),
+        marketplace_hash,
+        cep47_hash,
+        price,
+        cep78_hash,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    // Buy purse isn't funded when session knows trade is paid in tokens
+    let balance_before = get_account_balance(&mut builder, buyer);
+    let req = create_buy_nft_with_cep18_request(
+        buyer,
+        U256::from(1),
+        marketplace_hash,
+        cep47_hash,
+        price,
+        cep18_hash,
+    );
+    builder.exec(req).expect_success().commit();
+    assert!(balance_before - get_account_balance(&mut builder, buyer) < price);
+
+    // Session not naming a token pays natively, which token listing doesn't accept
+    let balance_before = get_account_balance(&mut builder, buyer);
+    let req = create_buy_nft_request(
+        buyer,
+        U256::from(0),
+        marketplace_hash,
+        cep78_hash,
+        price.as_u64(),
+        1,
+        0,
+    );
+    builder.exec(req).expect_failure().commit();
+    assert!(balance_before - get_account_balance(&mut builder, buyer) < price);
+    assert_eq!(get_pending_balance(&mut builder, marketplace_hash, buyer), U512::zero());
+
+    let req = create_buy_nft_with_cep18_request(
+        buyer,
+        U256::from(0),
+        marketplace_hash,
+        cep78_hash,
+        price,
+        cep18_hash,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Offer in tokens is held from allowance
+    let balance_before = get_account_balance(&mut builder, buyer);
+    let req = create_make_offer_with_payment_token_request(
+        buyer,
+        U256::from(1),
+        marketplace_hash,
+        cep47_hash,
+        price,
+        15,
+        cep18_hash,
+    );
+    builder.exec(req).expect_success().commit();
+    assert!(balance_before - get_account_balance(&mut builder, buyer) < price);
+
+    assert_eq!(get_cep_18_balance(&mut builder, cep18_hash, buyer.into()), U256::zero());
+}
@@ -0,0 +1,228 @@
+use casper_types::{U256, U512};
+
+use crate::helpers::{
+    blockchain_helpers::get_user,
+    cep18_helpers::deploy_cep_18,
+    cep47_helpers::approve_cep_47,
+    cep78_helpers::approve_cep_78,
+    fixtures::get_default_fixture,
+    marketplace_actions::{
+        build_register_collection_request, build_set_curated_mode_request,
+        build_unverify_collection_request, create_buy_nft_request, create_listing,
+        create_make_offer_request,
+    },
+};
+
+#[test]
+fn should_use_registered_standard_over_detected_one() {
+    let (mut builder, marketplace_hash, cep47_hash, _cep78_hash, marketplace_package_hash) =
+        get_default_fixture();
+
+    let installer = get_user(&mut builder, 0);
+    let seller = get_user(&mut builder, 2);
+    let listing_price = U512::from(1_000_000_000_000u64);
+
+    approve_cep_47(
+        &mut builder,
+        seller.into(),
+        cep47_hash,
+        marketplace_package_hash.into(),
+        vec![U256::from(1)],
+    );
+
+    // Only admins can register collections
+    let req = build_register_collection_request(seller, marketplace_hash, cep47_hash, 0, 0);
+    builder.exec(req).expect_failure().commit();
+
+    // Unknown standard is rejected
+    let req = build_register_collection_request(installer, marketplace_hash, cep47_hash, 2, 0);
+    builder.exec(req).expect_failure().commit();
+
+    // Registry wins over detection
+    let req = build_register_collection_request(installer, marketplace_hash, cep47_hash, 1, 0);
+    builder.exec(req).expect_success().commit();
+
+    let req = create_listing(seller, marketplace_hash, cep47_hash, 1, listing_price, 15, 0);
+    builder.exec(req).expect_failure().commit();
+
+    // Admin can correct registered standard
+    let req = build_register_collection_request(installer, marketplace_hash, cep47_hash, 0, 0);
+    builder.exec(req).expect_success().commit();
+
+    let req = create_listing(seller, marketplace_hash, cep47_hash, 1, listing_price, 15, 1);
+    builder.exec(req).expect_success().commit();
+}
+
+#[test]
+fn should_detect_standard_of_unregistered_collection_from_token_contract() {
+    let (mut builder, marketplace_hash, cep47_hash, cep78_hash, marketplace_package_hash) =
+        get_default_fixture();
+
+    let buyer = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let listing_price = U512::from(1_000_000_000_000u64);
+    let cep18_hash = deploy_cep_18(&mut builder);
+
+    approve_cep_47(
+        &mut builder,
+        seller.into(),
+        cep47_hash,
+        marketplace_package_hash.into(),
+        vec![U256::from(1)],
+    );
+    approve_cep_78(&mut builder, seller.into(), cep78_hash, marketplace_hash.into(), 0);
+
+    // Wrong caller hints are ignored
+    let req = create_listing(seller, marketplace_hash, cep47_hash, 1, listing_price, 15, 1);
+    builder.exec(req).expect_success().commit();
+
+    let req = create_listing(seller, marketplace_hash, cep78_hash, 0, listing_price, 15, 0);
+    builder.exec(req).expect_success().commit();
+
+    let req = create_buy_nft_request(
+        buyer,
+        U256::from(1),
+        marketplace_hash,
+        cep47_hash,
+        listing_price.as_u64(),
+        1,
+        0,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let req = create_buy_nft_request(
+        buyer,
+        U256::from(0),
+        marketplace_hash,
+        cep78_hash,
+        listing_price.as_u64(),
+        0,
+        0,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Contract that is neither standard can't be traded, whatever caller claims
+    let req = create_listing(seller, marketplace_hash, cep18_hash, 1, listing_price, 15, 0);
+    builder.exec(req).expect_failure().commit();
+
+    let req = create_listing(seller, marketplace_hash, cep18_hash, 1, listing_price, 15, 1);
+    builder.exec(req).expect_failure().commit();
+}
+
+#[test]
+fn should_only_trade_verified_collections_in_curated_mode() {
+    let (mut builder, marketplace_hash, cep47_hash, cep78_hash, marketplace_package_hash) =
+        get_default_fixture();
+
+    let installer = get_user(&mut builder, 0);
+    let offerer = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let listing_price = U512::from(1_000_000_000_000u64);
+
+    approve_cep_47(
+        &mut builder,
+        seller.into(),
+        cep47_hash,
+        marketplace_package_hash.into(),
+        vec![U256::from(1)],
+    );
+    approve_cep_78(&mut builder, seller, cep78_hash, marketplace_hash.into(), 0);
+
+    // Only admins can switch modes
+    let req = build_set_curated_mode_request(seller, marketplace_hash, true);
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_set_curated_mode_request(installer, marketplace_hash, true);
+    builder.exec(req).expect_success().commit();
+
+    // Unregistered collection can't be listed or offered on
+    let req = create_listing(seller, marketplace_hash, cep47_hash, 1, listing_price, 15, 0);
+    builder.exec(req).expect_failure().commit();
+
+    let req = create_make_offer_request(
+        offerer,
+        U256::from(1),
+        marketplace_hash,
+        cep47_hash,
+        1_000_000_000,
+        15,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    // Registered collection is verified
+    let req = build_register_collection_request(installer, marketplace_hash, cep47_hash, 0, 0);
+    builder.exec(req).expect_success().commit();
+
+    let req = create_listing(seller, marketplace_hash, cep47_hash, 1, listing_price, 15, 0);
+    builder.exec(req).expect_success().commit();
+
+    // Known but unverified collection stays blocked until open mode is back
+    let req = build_unverify_collection_request(installer, marketplace_hash, cep78_hash, 1);
+    builder.exec(req).expect_success().commit();
+
+    let req = create_listing(seller, marketplace_hash, cep78_hash, 0, listing_price, 15, 1);
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_set_curated_mode_request(installer, marketplace_hash, false);
+    builder.exec(req).expect_success().commit();
+
+    let req = create_listing(seller, marketplace_hash, cep78_hash, 0, listing_price, 15, 1);
+    builder.exec(req).expect_success().commit();
+}
+
+#[test]
+fn should_not_sell_listing_after_collection_loses_verification() {
+    let (mut builder, marketplace_hash, cep47_hash, _cep78_hash, marketplace_package_hash) =
+        get_default_fixture();
+
+    let installer = get_user(&mut builder, 0);
+    let buyer = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let listing_price = U512::from(1_000_000_000_000u64);
+
+    approve_cep_47(
+        &mut builder,
+        seller.into(),
+        cep47_hash,
+        marketplace_package_hash.into(),
+        vec![U256::from(1)],
+    );
+
+    let req = build_set_curated_mode_request(installer, marketplace_hash, true);
+    builder.exec(req).expect_success().commit();
+
+    let req = build_register_collection_request(installer, marketplace_hash, cep47_hash, 0, 0);
+    builder.exec(req).expect_success().commit();
+
+    let req = create_listing(seller, marketplace_hash, cep47_hash, 1, listing_price, 15, 0);
+    builder.exec(req).expect_success().commit();
+
+    // Listing created while verified can't be bought once verification is revoked
+    let req = build_unverify_collection_request(installer, marketplace_hash, cep47_hash, 0);
+    builder.exec(req).expect_success().commit();
+
+    let req = create_buy_nft_request(
+        buyer,
+        U256::from(1),
+        marketplace_hash,
+        cep47_hash,
+        listing_price.as_u64(),
+        0,
+        0,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_register_collection_request(installer, marketplace_hash, cep47_hash, 0, 0);
+    builder.exec(req).expect_success().commit();
+
+    let req = create_buy_nft_request(
+        buyer,
+        U256::from(1),
+        marketplace_hash,
+        cep47_hash,
+        listing_price.as_u64(),
+        0,
+        0,
+    );
+    builder.exec(req).expect_success().commit();
+}
@@ -0,0 +1,210 @@
+use casper_types::{Key, U256, U512};
+
+use crate::helpers::{
+    blockchain_helpers::{get_pending_balance, get_user},
+    constants::MARKETPLACE_FEE_PERCENTAGE,
+    fixtures::get_listing_created_fixture,
+    marketplace_actions::{
+        build_set_collection_fee_request, build_set_fee_recipient_request,
+        build_set_marketplace_fee_request, build_set_royalties_request, create_buy_nft_request,
+    },
+};
+
+#[test]
+fn should_let_only_installer_set_marketplace_fee() {
+    let (
+        mut builder,
+        marketplace_hash,
+        _cep47_hash,
+        _cep78_hash,
+        _marketplace_package_hash,
+        _listing_price,
+        _listing_duration_minutes,
+    ) = get_listing_created_fixture();
+
+    let installer = get_user(&mut builder, 0);
+    let seller = get_user(&mut builder, 2);
+
+    let req = build_set_marketplace_fee_request(seller, marketplace_hash, 0);
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_set_fee_recipient_request(seller, marketplace_hash, Some(seller.into()));
+    builder.exec(req).expect_failure().commit();
+
+    // Fee above 100% is rejected
+    let req = build_set_marketplace_fee_request(installer, marketplace_hash, 10001);
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_set_marketplace_fee_request(installer, marketplace_hash, 250);
+    builder.exec(req).expect_success().commit();
+
+    // Fees can only be credited to accounts
+    let req = build_set_fee_recipient_request(
+        installer,
+        marketplace_hash,
+        Some(Key::Hash(marketplace_hash.value())),
+    );
+    builder.exec(req).expect_failure().commit();
+}
+
+#[test]
+fn should_pay_configured_fee_to_fee_recipient() {
+    let (
+        mut builder,
+        marketplace_hash,
+        cep47_hash,
+        _cep78_hash,
+        _marketplace_package_hash,
+        listing_price,
+        _listing_duration_minutes,
+    ) = get_listing_created_fixture();
+
+    let installer = get_user(&mut builder, 0);
+    let seller = get_user(&mut builder, 2);
+    let fee_recipient = get_user(&mut builder, 3);
+    let fee_basis_points = 250u64;
+
+    let req = build_set_marketplace_fee_request(installer, marketplace_hash, fee_basis_points);
+    builder.exec(req).expect_success().commit();
+
+    let req =
+        build_set_fee_recipient_request(installer, marketplace_hash, Some(fee_recipient.into()));
+    builder.exec(req).expect_success().commit();
+
+    let balance_recipient_before =
+        get_pending_balance(&mut builder, marketplace_hash, fee_recipient);
+    let balance_seller_before = get_pending_balance(&mut builder, marketplace_hash, seller);
+
+    // For CEP47
+    let req = create_buy_nft_request(
+        get_user(&mut builder, 1),
+        U256::from(1),
+        marketplace_hash,
+        cep47_hash,
+        listing_price.as_u64(),
+        0,
+        0,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let balance_recipient_after =
+        get_pending_balance(&mut builder, marketplace_hash, fee_recipient);
+    let balance_seller_after = get_pending_balance(&mut builder, marketplace_hash, seller);
+
+    let expected_fee = listing_price * U512::from(fee_basis_points) / U512::from(10000);
+
+    assert_eq!(
+        expected_fee,
+        balance_recipient_after - balance_recipient_before
+    );
+    assert_eq!(
+        listing_price - expected_fee,
+        balance_seller_after - balance_seller_before
+    );
+}
+
+#[test]
+fn should_apply_collection_fee_override_before_default() {
+    let (
+        mut builder,
+        marketplace_hash,
+        cep47_hash,
+        cep78_hash,
+        _marketplace_package_hash,
+        listing_price,
+        _listing_duration_minutes,
+    ) = get_listing_created_fixture();
+
+    let installer = get_user(&mut builder, 0);
+    let seller = get_user(&mut builder, 2);
+
+    // Only installer can set collection fee
+    let req = build_set_collection_fee_request(seller, marketplace_hash, cep47_hash, Some(0));
+    builder.exec(req).expect_failure().commit();
+
+    // Partner collection trades without marketplace fee
+    let req = build_set_collection_fee_request(installer, marketplace_hash, cep47_hash, Some(0));
+    builder.exec(req).expect_success().commit();
+
+    let balance_seller_before = get_pending_balance(&mut builder, marketplace_hash, seller);
+
+    // For CEP47
+    let req = create_buy_nft_request(
+        get_user(&mut builder, 1),
+        U256::from(1),
+        marketplace_hash,
+        cep47_hash,
+        listing_price.as_u64(),
+        0,
+        0,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let balance_seller_after = get_pending_balance(&mut builder, marketplace_hash, seller);
+    assert_eq!(listing_price, balance_seller_after - balance_seller_before);
+
+    let balance_seller_before = get_pending_balance(&mut builder, marketplace_hash, seller);
+
+    // For CEP78 default fee still applies
+    let req = create_buy_nft_request(
+        get_user(&mut builder, 1),
+        U256::from(0),
+        marketplace_hash,
+        cep78_hash,
+        listing_price.as_u64(),
+        1,
+        0,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let balance_seller_after = get_pending_balance(&mut builder, marketplace_hash, seller);
+    let expected_fee = listing_price * U512::from(MARKETPLACE_FEE_PERCENTAGE) / U512::from(100);
+    assert_eq!(
+        listing_price - expected_fee,
+        balance_seller_after - balance_seller_before
+    );
+}
+
+#[test]
+fn should_reject_fee_and_royalty_above_price() {
+    let (
+        mut builder,
+        marketplace_hash,
+        cep47_hash,
+        _cep78_hash,
+        _marketplace_package_hash,
+        listing_price,
+        _listing_duration_minutes,
+    ) = get_listing_created_fixture();
+
+    let installer = get_user(&mut builder, 0);
+    let creator = get_user(&mut builder, 3);
+
+    // Royalty can't take what default fee already takes
+    let req = build_set_royalties_request(installer, creator, marketplace_hash, cep47_hash, 95);
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_set_royalties_request(installer, creator, marketplace_hash, cep47_hash, 50);
+    builder.exec(req).expect_success().commit();
+
+    // Collection override is checked against collection royalty
+    let req =
+        build_set_collection_fee_request(installer, marketplace_hash, cep47_hash, Some(5001));
+    builder.exec(req).expect_failure().commit();
+
+    // Global fee is raised after royalty was set, sale reverts instead of underpaying
+    let req = build_set_marketplace_fee_request(installer, marketplace_hash, 6000);
+    builder.exec(req).expect_success().commit();
+
+    // For CEP47
+    let req = create_buy_nft_request(
+        get_user(&mut builder, 1),
+        U256::from(1),
+        marketplace_hash,
+        cep47_hash,
+        listing_price.as_u64(),
+        0,
+        0,
+    );
+    builder.exec(req).expect_failure().commit();
+}
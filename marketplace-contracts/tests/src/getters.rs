@@ -0,0 +1,186 @@
+use casper_types::{Key, U256, U512};
+
+use crate::helpers::{
+    blockchain_helpers::{get_getter_fields, get_user},
+    cep47_helpers::approve_cep_47,
+    fixtures::{get_default_fixture, get_listing_created_fixture, get_offers_created_fixture},
+    marketplace_actions::{
+        build_get_auction_request, build_get_listing_request, build_get_marketplace_fee_request,
+        build_get_offer_request, build_get_royalty_request, build_get_token_metadata_request,
+        build_read_getter_request, build_set_royalties_request, build_start_auction_request,
+    },
+};
+
+#[test]
+fn should_read_listing_and_fee_through_getters() {
+    let (
+        mut builder,
+        marketplace_hash,
+        cep47_hash,
+        _cep78_hash,
+        _marketplace_package_hash,
+        listing_price,
+        listing_duration_minutes,
+    ) = get_listing_created_fixture();
+
+    let user = get_user(&mut builder, 1);
+
+    let req = build_get_marketplace_fee_request(user, marketplace_hash);
+    builder.exec(req).expect_success().commit();
+
+    let req = build_get_listing_request(user, marketplace_hash, cep47_hash, U256::from(1));
+    builder.exec(req).expect_success().commit();
+
+    let req = build_read_getter_request(
+        user,
+        marketplace_hash,
+        "get_listing",
+        cep47_hash,
+        U256::from(1),
+        None,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Native listing has no payment token field
+    let fields = get_getter_fields(&mut builder, user, "get_listing");
+    let seller = get_user(&mut builder, 2);
+    assert_eq!(fields.len(), 4);
+    assert_eq!(fields["seller"], Key::Account(seller).to_formatted_string());
+    assert_eq!(fields["price"], listing_price.to_string());
+    assert_eq!(fields["expiration_time"], (listing_duration_minutes * 60_000).to_string());
+    assert_eq!(fields["start_time"], "0");
+
+    // Token that was never listed
+    let req = build_get_listing_request(user, marketplace_hash, cep47_hash, U256::from(99));
+    builder.exec(req).expect_failure().commit();
+}
+
+#[test]
+fn should_read_token_metadata_for_both_standards() {
+    let (
+        mut builder,
+        marketplace_hash,
+        cep47_hash,
+        cep78_hash,
+        _marketplace_package_hash,
+        _listing_price,
+        _listing_duration_minutes,
+    ) = get_listing_created_fixture();
+
+    let user = get_user(&mut builder, 1);
+
+    let req = build_get_token_metadata_request(user, marketplace_hash, cep47_hash, U256::from(1));
+    builder.exec(req).expect_success().commit();
+
+    let req = build_get_token_metadata_request(user, marketplace_hash, cep78_hash, U256::from(0));
+    builder.exec(req).expect_success().commit();
+}
+
+#[test]
+fn should_read_offer_through_getter() {
+    let (
+        mut builder,
+        marketplace_hash,
+        cep47_hash,
+        _cep78_hash,
+        _marketplace_package_hash,
+        offer_amount,
+        offer_duration,
+        offerer,
+    ) = get_offers_created_fixture();
+
+    let user = get_user(&mut builder, 1);
+
+    let req = build_get_offer_request(user, marketplace_hash, cep47_hash, U256::from(1), offerer);
+    builder.exec(req).expect_success().commit();
+
+    let req = build_read_getter_request(
+        user,
+        marketplace_hash,
+        "get_offer",
+        cep47_hash,
+        U256::from(1),
+        Some(offerer),
+    );
+    builder.exec(req).expect_success().commit();
+
+    let fields = get_getter_fields(&mut builder, user, "get_offer");
+    assert_eq!(fields.len(), 2);
+    assert_eq!(fields["price"], offer_amount.to_string());
+    assert_eq!(fields["expiration_time"], (offer_duration * 60_000).to_string());
+
+    // Account that made no offer
+    let req = build_get_offer_request(user, marketplace_hash, cep47_hash, U256::from(1), user);
+    builder.exec(req).expect_failure().commit();
+}
+
+#[test]
+fn should_read_auction_through_getter() {
+    let (mut builder, marketplace_hash, cep47_hash, _cep78_hash, marketplace_package_hash) =
+        get_default_fixture();
+
+    let user = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+
+    // Token that was never auctioned
+    let req = build_get_auction_request(user, marketplace_hash, cep47_hash, U256::from(1));
+    builder.exec(req).expect_failure().commit();
+
+    approve_cep_47(
+        &mut builder,
+        seller,
+        cep47_hash,
+        marketplace_package_hash.into(),
+        vec![U256::from(1)],
+    );
+
+    let req = build_start_auction_request(seller, marketplace_hash, cep47_hash, U256::from(1), 0);
+    builder.exec(req).expect_success().commit();
+
+    let req = build_get_auction_request(user, marketplace_hash, cep47_hash, U256::from(1));
+    builder.exec(req).expect_success().commit();
+
+    let req = build_read_getter_request(
+        user,
+        marketplace_hash,
+        "get_auction",
+        cep47_hash,
+        U256::from(1),
+        None,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Auction without bids, reserve or buy now price leaves those fields out
+    let fields = get_getter_fields(&mut builder, user, "get_auction");
+    assert_eq!(fields.len(), 12);
+    assert_eq!(fields["seller"], seller.to_formatted_string());
+    assert_eq!(fields["starting_price"], U512::from(10).to_string());
+    assert_eq!(fields["current_bid"], U512::from(10).to_string());
+    assert_eq!(fields["current_winner"], seller.to_formatted_string());
+    assert_eq!(fields["end_time"], (20 * 60_000).to_string());
+    assert_eq!(fields["start_time"], "0");
+    assert_eq!(fields["extension_count"], "0");
+    assert!(!fields.contains_key("reserve_commitment"));
+    assert!(!fields.contains_key("buy_now_price"));
+    assert!(!fields.contains_key("payment_token"));
+}
+
+#[test]
+fn should_read_royalty_through_getter() {
+    let (mut builder, marketplace_hash, cep47_hash, _cep78_hash, _marketplace_package_hash) =
+        get_default_fixture();
+
+    let installer = get_user(&mut builder, 0);
+    let user = get_user(&mut builder, 1);
+    let creator = get_user(&mut builder, 3);
+
+    // Collection without royalty reads as none instead of failing
+    let req = build_get_royalty_request(user, marketplace_hash, cep47_hash);
+    builder.exec(req).expect_success().commit();
+
+    let req = build_set_royalties_request(installer, creator, marketplace_hash, cep47_hash, 5);
+    builder.exec(req).expect_success().commit();
+
+    let req = build_get_royalty_request(user, marketplace_hash, cep47_hash);
+    builder.exec(req).expect_success().commit();
+}
@@ -1,4 +1,4 @@
-use std::usize;
+use std::{collections::BTreeMap, usize};
 
 use casper_engine_test_support::{
     ExecuteRequestBuilder, InMemoryWasmTestBuilder, WasmTestBuilder, DEFAULT_ACCOUNTS,
@@ -8,8 +8,10 @@ use casper_execution_engine::{
     core::engine_state::GenesisAccount, storage::global_state::in_memory::InMemoryGlobalState,
 };
 use casper_types::{
-    account::AccountHash, runtime_args, ContractHash, Key, Motes, PublicKey, RuntimeArgs,
-    SecretKey, U512,
+    account::AccountHash,
+    bytesrepr::{Bytes, FromBytes, ToBytes},
+    crypto::blake2b,
+    runtime_args, ContractHash, Key, Motes, PublicKey, RuntimeArgs, SecretKey, U512,
 };
 use sha2::{Digest, Sha256};
 
@@ -26,6 +28,113 @@ pub fn get_account_balance(
     builder.get_purse_balance(main_purse)
 }
 
+/// Native balance credited to account by marketplace and not yet withdrawn
+pub fn get_pending_balance(
+    builder: &mut InMemoryWasmTestBuilder,
+    marketplace_hash: ContractHash,
+    account_hash: AccountHash,
+) -> U512 {
+    // Escrow dictionary is created on first use
+    let escrow = match builder
+        .get_contract(marketplace_hash)
+        .expect("should have marketplace contract")
+        .named_keys()
+        .get("escrow")
+    {
+        Some(key) => key.into_uref().expect("should be uref"),
+        None => return U512::zero(),
+    };
+
+    let key: String = account_hash
+        .value()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+
+    match builder.query_dictionary_item(None, escrow, &key) {
+        Ok(value) => value
+            .as_cl_value()
+            .expect("should be cl value")
+            .clone()
+            .into_t()
+            .expect("should be U512"),
+        Err(_) => U512::zero(),
+    }
+}
+
+/// Hex blake2b of reserve price bytes followed by salt, as marketplace checks it on reveal
+pub fn get_reserve_commitment(reserve_price: U512, salt: &str) -> String {
+    let mut bytes = reserve_price.to_bytes().unwrap();
+    bytes.extend_from_slice(salt.as_bytes());
+
+    blake2b(bytes)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Names of CES events emitted by marketplace, oldest first
+pub fn get_event_names(
+    builder: &mut InMemoryWasmTestBuilder,
+    marketplace_hash: ContractHash,
+) -> Vec<String> {
+    let named_keys = builder
+        .get_contract(marketplace_hash)
+        .expect("should have marketplace contract")
+        .named_keys()
+        .clone();
+    let events = named_keys
+        .get("__events")
+        .and_then(Key::as_uref)
+        .copied()
+        .expect("should have events dictionary");
+    let length: u32 = builder
+        .query(None, named_keys["__events_length"], &[])
+        .expect("should have events length")
+        .as_cl_value()
+        .expect("should be cl value")
+        .clone()
+        .into_t()
+        .expect("should be u32");
+
+    (0..length)
+        .map(|index| {
+            let event: Bytes = builder
+                .query_dictionary_item(None, events, &index.to_string())
+                .expect("should have event")
+                .as_cl_value()
+                .expect("should be cl value")
+                .clone()
+                .into_t()
+                .expect("should be bytes");
+            let (name, _) = String::from_bytes(&event).expect("should start with name");
+            name
+        })
+        .collect()
+}
+
+/// Fields returned by getter, as stored by getter session under entry point name
+pub fn get_getter_fields(
+    builder: &mut InMemoryWasmTestBuilder,
+    caller: AccountHash,
+    entry_point: &str,
+) -> BTreeMap<String, String> {
+    let key = *builder
+        .get_expected_account(caller)
+        .named_keys()
+        .get(entry_point)
+        .expect("should have getter result");
+
+    builder
+        .query(None, key, &[])
+        .expect("should have getter fields")
+        .as_cl_value()
+        .expect("should be cl value")
+        .clone()
+        .into_t()
+        .expect("should be fields map")
+}
+
 /// Retrieves the contract hash from the default account's storage by a given key
 pub fn get_contract_hash_from_account(
     builder: &mut InMemoryWasmTestBuilder,
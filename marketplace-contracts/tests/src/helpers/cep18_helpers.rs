@@ -0,0 +1,93 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{
+    account::AccountHash, bytesrepr::ToBytes, crypto::blake2b, runtime_args, ContractHash, Key,
+    RuntimeArgs, U256,
+};
+
+use crate::helpers::{blockchain_helpers::get_contract_hash_from_account, constants::CEP18_WASM};
+
+pub fn deploy_cep_18(builder: &mut InMemoryWasmTestBuilder) -> ContractHash {
+    let request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP18_WASM,
+        runtime_args! {
+            "total_supply" => U256::from(1_000_000_000_000_000u64)
+        },
+    )
+    .build();
+    builder.exec(request).expect_success().commit();
+
+    get_contract_hash_from_account(builder, "cep18_contract_hash")
+}
+
+pub fn transfer_cep_18(
+    builder: &mut InMemoryWasmTestBuilder,
+    caller: AccountHash,
+    cep18_hash: ContractHash,
+    recipient: Key,
+    amount: U256,
+) {
+    let request = ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        cep18_hash,
+        "transfer",
+        runtime_args! {
+            "recipient" => recipient,
+            "amount" => amount
+        },
+    )
+    .build();
+    builder.exec(request).expect_success().commit();
+}
+
+pub fn approve_cep_18(
+    builder: &mut InMemoryWasmTestBuilder,
+    caller: AccountHash,
+    cep18_hash: ContractHash,
+    spender: Key,
+    amount: U256,
+) {
+    let request = ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        cep18_hash,
+        "approve",
+        runtime_args! {
+            "spender" => spender,
+            "amount" => amount
+        },
+    )
+    .build();
+    builder.exec(request).expect_success().commit();
+}
+
+pub fn get_cep_18_balance(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_hash: ContractHash,
+    owner: Key,
+) -> U256 {
+    let balances = builder
+        .get_contract(cep18_hash)
+        .expect("should have token contract")
+        .named_keys()
+        .get("balances")
+        .expect("should have balances")
+        .into_uref()
+        .expect("should be uref");
+
+    let key: String = blake2b(owner.to_bytes().unwrap())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+
+    match builder.query_dictionary_item(None, balances, &key) {
+        Ok(value) => value
+            .as_cl_value()
+            .expect("should be cl value")
+            .clone()
+            .into_t()
+            .expect("should be U256"),
+        Err(_) => U256::zero(),
+    }
+}
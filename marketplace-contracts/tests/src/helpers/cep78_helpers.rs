@@ -1,7 +1,9 @@
 use std::collections::BTreeMap;
 
 use casper_engine_test_support::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS, DEFAULT_ACCOUNT_ADDR};
-use casper_types::{account::AccountHash, runtime_args, ContractHash, Key, RuntimeArgs, U256};
+use casper_types::{
+    account::AccountHash, crypto::blake2b, runtime_args, ContractHash, Key, RuntimeArgs, U256,
+};
 
 use crate::helpers::{blockchain_helpers::get_contract_hash_from_account, constants::CEP78_WASM};
 
@@ -60,6 +62,77 @@ pub fn deploy_cep_78(builder: &mut InMemoryWasmTestBuilder) -> ContractHash {
 
 
 
+/// Deploys a collection whose tokens are identified by metadata hash
+pub fn deploy_cep_78_hash_mode(builder: &mut InMemoryWasmTestBuilder) -> ContractHash {
+    let request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP78_WASM,
+        runtime_args! {
+            "collection_name" => "hashed",
+            "collection_symbol" => "my symbol",
+            "total_token_supply" => 100u64,
+            "ownership_mode" => 2u8,
+            "json_schema" => "",
+            "minting_mode" => 1u8,
+            "nft_kind" => 0u8,
+            "nft_metadata_kind" => 2u8,
+            "identifier_mode" => 1u8,
+            "metadata_mutability" => 0u8
+        },
+    )
+    .build();
+    builder.exec(request).expect_success().commit();
+
+    get_contract_hash_from_account(builder, "cep78_contract_hash_hashed")
+}
+
+/// Token hash CEP-78 assigns to a token minted with given raw metadata
+pub fn get_cep_78_token_hash(token_meta_data: &str) -> String {
+    blake2b(token_meta_data)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+pub fn approve_cep_78_by_hash(
+    builder: &mut InMemoryWasmTestBuilder,
+    caller: AccountHash,
+    cep78_hash: ContractHash,
+    recipient: Key,
+    token_hash: String,
+) {
+    let request = ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        cep78_hash,
+        "approve",
+        runtime_args! {
+            "operator" => recipient,
+            "token_hash" => token_hash,
+        },
+    )
+    .build();
+    builder.exec(request).expect_success().commit();
+}
+
+pub fn approve_all_cep_78(
+    builder: &mut InMemoryWasmTestBuilder,
+    caller: AccountHash,
+    cep78_hash: ContractHash,
+    operator: Key,
+) {
+    let request = ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        cep78_hash,
+        "set_approval_for_all",
+        runtime_args! {
+            "approve_all" => true,
+            "operator" => operator,
+        },
+    )
+    .build();
+    builder.exec(request).expect_success().commit();
+}
+
 pub fn approve_cep_78(
         builder: &mut InMemoryWasmTestBuilder,
         caller: AccountHash,
@@ -3,12 +3,17 @@ use casper_types::account::AccountHash;
 
     // Contract Wasm File Paths (Constants)
     pub const MARKETPLACE_WASM: &str = "contract.wasm";
+    // First released version, installed to test upgrades of existing deployments
+    pub const MARKETPLACE_V1_WASM: &str = "contract-v1.wasm";
     pub const CEP47_WASM: &str = "cep47-token.wasm";
     pub const CEP78_WASM: &str = "cep78-token.wasm";
+    pub const CEP18_WASM: &str = "cep18-token.wasm";
 
     pub const PAYMENT_WASM: &str = "payment-call.wasm";
     pub const OFFER_WASM: &str = "make-offer-call.wasm";
     pub const BID_WASM: &str = "bid-call.wasm";
+    pub const BUY_BATCH_WASM: &str = "buy-batch-call.wasm";
+    pub const GETTER_WASM: &str = "getter-call.wasm";
 
     // Contract Storage Keys (Constants)
     pub const CONTRACT_KEY: &str = "mystra_marketplace";
@@ -18,4 +23,13 @@ use casper_types::account::AccountHash;
     pub const ENTRY_POINT_ACCEPT_OFFER: &str = "accept_offer";
     pub const ENTRY_POINT_CANCEL_OFFER: &str = "cancel_offer";
 
-    pub const MARKETPLACE_FEE_PERCENTAGE : u64 = 10;
\ No newline at end of file
+    pub const MARKETPLACE_FEE_PERCENTAGE : u64 = 10;
+
+    // Marketplace roles (Constants)
+    pub const ROLE_ADMIN: u8 = 0;
+    pub const ROLE_ROYALTY_MANAGER: u8 = 1;
+    pub const ROLE_PAUSER: u8 = 3;
+    pub const ROLE_MODERATOR: u8 = 4;
+
+    // Pausable features (Constants)
+    pub const FEATURE_LISTINGS: u8 = 1;
@@ -2,13 +2,14 @@ use casper_engine_test_support::{
     ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
     PRODUCTION_RUN_GENESIS_REQUEST,
 };
+use casper_execution_engine::core::engine_state::ExecuteRequest;
 use casper_types::{account::AccountHash, runtime_args, ContractHash, RuntimeArgs, U256, U512};
 
 use super::{
     blockchain_helpers::{get_contract_hash_from_account, get_user},
     cep47_helpers::{approve_cep_47, deploy_cep_47, mint_cep_47},
     cep78_helpers::{approve_cep_78, deploy_cep_78, mint_cep_78},
-    constants::{CONTRACT_KEY, MARKETPLACE_WASM},
+    constants::{CONTRACT_KEY, MARKETPLACE_V1_WASM, MARKETPLACE_WASM},
     marketplace_actions::{build_set_royalties_request, create_listing, create_make_offer_request},
 };
 
@@ -18,6 +19,29 @@ pub fn get_default_fixture() -> (
     ContractHash,
     ContractHash,
     ContractHash,
+) {
+    get_fixture(MARKETPLACE_WASM)
+}
+
+/// Same as default fixture, but with the first released marketplace version installed
+pub fn get_v1_fixture() -> (
+    InMemoryWasmTestBuilder,
+    ContractHash,
+    ContractHash,
+    ContractHash,
+    ContractHash,
+) {
+    get_fixture(MARKETPLACE_V1_WASM)
+}
+
+fn get_fixture(
+    marketplace_wasm: &str,
+) -> (
+    InMemoryWasmTestBuilder,
+    ContractHash,
+    ContractHash,
+    ContractHash,
+    ContractHash,
 ) {
     let mut builder = InMemoryWasmTestBuilder::default();
     builder
@@ -25,7 +49,7 @@ pub fn get_default_fixture() -> (
         .commit();
 
     // Deploy contracts
-    let marketplace_hash = deploy_marketplace(&mut builder, MARKETPLACE_WASM);
+    let marketplace_hash = deploy_marketplace(&mut builder, marketplace_wasm);
     let nft_hash = deploy_cep_47(&mut builder);
     let nft_hash_78: ContractHash = deploy_cep_78(&mut builder);
 
@@ -235,6 +259,19 @@ pub fn get_listing_created_fixture_with_royalties() -> (
     )
 }
 
+/// Installs the marketplace again, which adds a new version to the existing package
+pub fn upgrade_marketplace(builder: &mut InMemoryWasmTestBuilder) -> ContractHash {
+    let request = build_upgrade_marketplace_request();
+    builder.exec(request).expect_success().commit();
+    get_contract_hash_from_account(builder, CONTRACT_KEY)
+}
+
+/// Upgrade request, for upgrades that are expected to fail
+pub fn build_upgrade_marketplace_request() -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, MARKETPLACE_WASM, runtime_args! {})
+        .build()
+}
+
 /// Deploys a contract version to the InMemoryWasmTestBuilder
 fn deploy_marketplace(builder: &mut InMemoryWasmTestBuilder, wasm_code: &str) -> ContractHash {
     let request =
@@ -2,9 +2,9 @@ use casper_engine_test_support::{
     ExecuteRequestBuilder, DEFAULT_ACCOUNTS
 };
 use casper_execution_engine::core::engine_state::ExecuteRequest;
-use casper_types::{account::AccountHash, runtime_args, ContractHash, RuntimeArgs, U256, U512};
+use casper_types::{account::AccountHash, runtime_args, ContractHash, Key, RuntimeArgs, U256, U512};
 
-use super::constants::{BID_WASM, ENTRY_POINT_ACCEPT_OFFER, ENTRY_POINT_CANCEL_OFFER, ENTRY_POINT_CREATE_LISTING, OFFER_WASM, PAYMENT_WASM};
+use super::constants::{BID_WASM, BUY_BATCH_WASM, ENTRY_POINT_ACCEPT_OFFER, ENTRY_POINT_CANCEL_OFFER, ENTRY_POINT_CREATE_LISTING, GETTER_WASM, OFFER_WASM, PAYMENT_WASM};
 
 
 pub fn create_listing(
@@ -32,208 +32,1434 @@ pub fn create_listing(
     .build()
 }
 
-pub fn create_buy_nft_request(
+/// Creates listing that can't be bought before start time
+pub fn create_scheduled_listing(
     caller: AccountHash,
-    token_id: U256,
     marketplace_hash: ContractHash,
-    contract_hash: ContractHash,
-    amount_paid: u64,
+    nft_hash: ContractHash,
+    token_id: u64,
+    price: U512,
+    duration_minutes: u64,
+    start_time: u64,
     standard: u8,
-    blocktime: u64
 ) -> ExecuteRequest {
-    ExecuteRequestBuilder::standard(
+    ExecuteRequestBuilder::contract_call_by_hash(
         caller,
-        PAYMENT_WASM,
+        marketplace_hash,
+        ENTRY_POINT_CREATE_LISTING,
         runtime_args! {
-            "token_id" => token_id,
-            "buy_contract_hash" => contract_hash.to_formatted_string(),
-            "marketplace_hash" => marketplace_hash.to_formatted_string(),
-            "amount" => U512::from(amount_paid),
+            "token_id" => U256::from(token_id),
+            "contract_hash" => nft_hash.to_formatted_string(),
+            "price" => price,
+            "duration_minutes" => duration_minutes,
+            "start_time" => start_time,
             "token_standard" => standard
         },
     )
-    .with_block_time(blocktime).build()
+    .build()
 }
 
-pub fn create_make_offer_request(
+pub fn build_create_listings_batch_request(
     caller: AccountHash,
-    token_id: U256,
     marketplace_hash: ContractHash,
-    contract_hash: ContractHash,
-    offered_amount: u64,
-    duration_minutes: u64
+    nft_hashes: Vec<ContractHash>,
+    token_ids: Vec<U256>,
+    prices: Vec<U512>,
+    durations: Vec<u64>,
 ) -> ExecuteRequest {
-    ExecuteRequestBuilder::standard(
+    let contract_hashes: Vec<String> = nft_hashes
+        .iter()
+        .map(|hash| hash.to_formatted_string())
+        .collect();
+
+    ExecuteRequestBuilder::contract_call_by_hash(
         caller,
-        OFFER_WASM,
+        marketplace_hash,
+        "create_listings_batch",
         runtime_args! {
-            "token_id" => token_id,
-            "offer_contract_hash" => contract_hash.to_formatted_string(),
-            "marketplace_hash" => marketplace_hash.to_formatted_string(),
-            "amount" => U512::from(offered_amount),
-            "duration_minutes" => duration_minutes
+            "contract_hashes" => contract_hashes,
+            "token_ids" => token_ids,
+            "prices" => prices,
+            "durations" => durations
         },
     )
     .build()
 }
 
-pub fn build_accept_offer_request(
+pub fn build_cancel_listings_batch_request(
     caller: AccountHash,
     marketplace_hash: ContractHash,
-    cep47_hash: ContractHash,
-    token_id: U256,
-    from: AccountHash,
-    token_standard: u8,
-    blocktime: u64
+    nft_hashes: Vec<ContractHash>,
+    token_ids: Vec<U256>,
 ) -> ExecuteRequest {
+    let contract_hashes: Vec<String> = nft_hashes
+        .iter()
+        .map(|hash| hash.to_formatted_string())
+        .collect();
 
     ExecuteRequestBuilder::contract_call_by_hash(
         caller,
         marketplace_hash,
-        ENTRY_POINT_ACCEPT_OFFER,
+        "cancel_listings_batch",
         runtime_args! {
-            "token_id" => token_id,
-            "contract_hash" => cep47_hash.to_formatted_string(),
-            "offerer" => from.to_formatted_string(),
-            "token_standard" => token_standard
+            "contract_hashes" => contract_hashes,
+            "token_ids" => token_ids
         },
     )
-    .with_block_time(blocktime).build()
+    .build()
 }
 
-pub fn build_cancel_offer_request(
+/// Batch where items with a token hash are passed by hash instead of id
+pub fn build_create_listings_batch_by_hash_request(
     caller: AccountHash,
     marketplace_hash: ContractHash,
-    cep47_hash: ContractHash,
-    token_id: U256,
+    nft_hashes: Vec<ContractHash>,
+    token_ids: Vec<U256>,
+    token_hashes: Vec<Option<String>>,
+    prices: Vec<U512>,
 ) -> ExecuteRequest {
+    let contract_hashes: Vec<String> = nft_hashes
+        .iter()
+        .map(|hash| hash.to_formatted_string())
+        .collect();
+    let durations: Vec<u64> = vec![0; token_ids.len()];
 
     ExecuteRequestBuilder::contract_call_by_hash(
         caller,
         marketplace_hash,
-        ENTRY_POINT_CANCEL_OFFER,
+        "create_listings_batch",
         runtime_args! {
-            "token_id" => token_id,
-            "contract_hash" => cep47_hash.to_formatted_string(),
+            "contract_hashes" => contract_hashes,
+            "token_ids" => token_ids,
+            "token_hashes" => token_hashes,
+            "prices" => prices,
+            "durations" => durations
         },
     )
     .build()
 }
 
-pub fn build_cancel_listing_request(
-    
+pub fn build_cancel_listings_batch_by_hash_request(
     caller: AccountHash,
     marketplace_hash: ContractHash,
-    cep47_hash: ContractHash,
-    token_id: U256,
+    nft_hashes: Vec<ContractHash>,
+    token_ids: Vec<U256>,
+    token_hashes: Vec<Option<String>>,
+) -> ExecuteRequest {
+    let contract_hashes: Vec<String> = nft_hashes
+        .iter()
+        .map(|hash| hash.to_formatted_string())
+        .collect();
+
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "cancel_listings_batch",
+        runtime_args! {
+            "contract_hashes" => contract_hashes,
+            "token_ids" => token_ids,
+            "token_hashes" => token_hashes
+        },
+    )
+    .build()
+}
+
+pub fn create_listing_by_hash(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    cep78_hash: ContractHash,
+    token_hash: String,
+    price: U512,
+    expiration_time: u64,
+) -> ExecuteRequest {
+
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        ENTRY_POINT_CREATE_LISTING,
+        runtime_args! {
+            "token_hash" => token_hash,
+            "contract_hash" => cep78_hash.to_formatted_string(),
+            "price" => price,
+            "duration_minutes" => expiration_time,
+            "token_standard" => 1u8
+        },
+    )
+    .build()
+}
+
+pub fn create_buy_nft_by_hash_request(
+    caller: AccountHash,
+    token_hash: String,
+    marketplace_hash: ContractHash,
+    contract_hash: ContractHash,
+    amount_paid: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        caller,
+        PAYMENT_WASM,
+        runtime_args! {
+            "token_hash" => token_hash,
+            "buy_contract_hash" => contract_hash.to_formatted_string(),
+            "marketplace_hash" => marketplace_hash.to_formatted_string(),
+            "amount" => U512::from(amount_paid),
+            "token_standard" => 1u8
+        },
+    )
+    .build()
+}
+
+pub fn create_listing_with_payment_token(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    nft_hash: ContractHash,
+    token_id: u64,
+    price: U512,
+    payment_token: ContractHash,
     standard: u8
 ) -> ExecuteRequest {
 
     ExecuteRequestBuilder::contract_call_by_hash(
         caller,
         marketplace_hash,
-        "cancel_listing",
+        ENTRY_POINT_CREATE_LISTING,
         runtime_args! {
-            "token_id" => token_id,
-            "contract_hash" => cep47_hash.to_formatted_string(),
+            "token_id" => U256::from(token_id),
+            "contract_hash" => nft_hash.to_formatted_string(),
+            "price" => price,
+            "duration_minutes" => 0u64,
+            "payment_token" => payment_token.to_formatted_string(),
             "token_standard" => standard
         },
     )
     .build()
 }
 
-pub fn build_start_auction_request(
+/// Buys listing priced in CEP-18 token, no purse is needed
+pub fn build_buy_listing_request(
     caller: AccountHash,
     marketplace_hash: ContractHash,
-    cep47_hash: ContractHash,
+    nft_hash: ContractHash,
     token_id: U256,
-    standard: u8
+    amount: U512,
+    payment_token: ContractHash,
 ) -> ExecuteRequest {
 
     ExecuteRequestBuilder::contract_call_by_hash(
         caller,
         marketplace_hash,
-        "start_auction",
+        "buy_listing",
         runtime_args! {
             "token_id" => token_id,
-            "contract_hash" => cep47_hash.to_formatted_string(),
-            "duration_minutes" => 20u64,
-            "price" => U512::from(10),
-            "token_standard" => standard
+            "contract_hash" => nft_hash.to_formatted_string(),
+            "amount" => amount,
+            "payment_token" => payment_token.to_formatted_string()
         },
     )
     .build()
 }
 
+/// Makes offer in CEP-18 token, no purse is needed
+pub fn build_make_offer_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    nft_hash: ContractHash,
+    token_id: U256,
+    amount: U512,
+    payment_token: ContractHash,
+) -> ExecuteRequest {
 
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "make_offer",
+        runtime_args! {
+            "token_id" => token_id,
+            "contract_hash" => nft_hash.to_formatted_string(),
+            "amount" => amount,
+            "duration_minutes" => 60u64,
+            "payment_token" => payment_token.to_formatted_string()
+        },
+    )
+    .build()
+}
 
-
-pub fn create_place_bid_request(
+pub fn create_buy_nft_request(
     caller: AccountHash,
     token_id: U256,
-    bid: U512,
     marketplace_hash: ContractHash,
     contract_hash: ContractHash,
+    amount_paid: u64,
+    standard: u8,
     blocktime: u64
 ) -> ExecuteRequest {
     ExecuteRequestBuilder::standard(
         caller,
-        BID_WASM,
+        PAYMENT_WASM,
         runtime_args! {
             "token_id" => token_id,
-            "bid_contract_hash" => contract_hash.to_formatted_string(),
+            "buy_contract_hash" => contract_hash.to_formatted_string(),
             "marketplace_hash" => marketplace_hash.to_formatted_string(),
-            "amount" => bid
+            "amount" => U512::from(amount_paid),
+            "token_standard" => standard
         },
     )
     .with_block_time(blocktime).build()
 }
 
+/// Funds buy purse with purse_amount, marketplace refunds whatever exceeds traded amount
+pub fn create_buy_nft_with_purse_amount_request(
+    caller: AccountHash,
+    token_id: U256,
+    marketplace_hash: ContractHash,
+    contract_hash: ContractHash,
+    amount: U512,
+    purse_amount: U512,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        caller,
+        PAYMENT_WASM,
+        runtime_args! {
+            "token_id" => token_id,
+            "buy_contract_hash" => contract_hash.to_formatted_string(),
+            "marketplace_hash" => marketplace_hash.to_formatted_string(),
+            "amount" => purse_amount,
+            "trade_amount" => amount
+        },
+    )
+    .build()
+}
 
-pub fn build_end_auction_request(
+pub fn create_buy_listings_batch_by_hash_request(
     caller: AccountHash,
     marketplace_hash: ContractHash,
-    cep47_hash: ContractHash,
+    nft_hashes: Vec<ContractHash>,
+    token_ids: Vec<U256>,
+    token_hashes: Vec<Option<String>>,
+    amount_paid: U512,
+) -> ExecuteRequest {
+    let contract_hashes: Vec<String> = nft_hashes
+        .iter()
+        .map(|hash| hash.to_formatted_string())
+        .collect();
+
+    ExecuteRequestBuilder::standard(
+        caller,
+        BUY_BATCH_WASM,
+        runtime_args! {
+            "buy_contract_hashes" => contract_hashes,
+            "token_ids" => token_ids,
+            "token_hashes" => token_hashes,
+            "marketplace_hash" => marketplace_hash.to_formatted_string(),
+            "amount" => amount_paid,
+            "skip_unavailable" => false
+        },
+    )
+    .build()
+}
+
+/// Buys listing priced in CEP-18 token through session, buy purse is left unfunded
+pub fn create_buy_nft_with_cep18_request(
+    caller: AccountHash,
     token_id: U256,
-    blocktime: u64,
-    standard: u8
+    marketplace_hash: ContractHash,
+    contract_hash: ContractHash,
+    price: U512,
+    payment_token: ContractHash,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        caller,
+        PAYMENT_WASM,
+        runtime_args! {
+            "token_id" => token_id,
+            "buy_contract_hash" => contract_hash.to_formatted_string(),
+            "marketplace_hash" => marketplace_hash.to_formatted_string(),
+            "amount" => price,
+            "payment_token" => payment_token.to_formatted_string()
+        },
+    )
+    .build()
+}
+
+pub fn create_buy_listings_batch_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    nft_hashes: Vec<ContractHash>,
+    token_ids: Vec<U256>,
+    amount_paid: U512,
+    skip_unavailable: bool,
+) -> ExecuteRequest {
+    let contract_hashes: Vec<String> = nft_hashes
+        .iter()
+        .map(|hash| hash.to_formatted_string())
+        .collect();
+
+    ExecuteRequestBuilder::standard(
+        caller,
+        BUY_BATCH_WASM,
+        runtime_args! {
+            "buy_contract_hashes" => contract_hashes,
+            "token_ids" => token_ids,
+            "marketplace_hash" => marketplace_hash.to_formatted_string(),
+            "amount" => amount_paid,
+            "skip_unavailable" => skip_unavailable
+        },
+    )
+    .build()
+}
 
+pub fn build_create_bundle_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    nft_hashes: Vec<ContractHash>,
+    token_ids: Vec<U256>,
+    price: U512,
+    duration_minutes: u64,
 ) -> ExecuteRequest {
+    let contract_hashes: Vec<String> = nft_hashes
+        .iter()
+        .map(|hash| hash.to_formatted_string())
+        .collect();
 
     ExecuteRequestBuilder::contract_call_by_hash(
         caller,
         marketplace_hash,
-        "end_auction",
+        "create_bundle",
         runtime_args! {
-            "token_id" => token_id,
-            "contract_hash" => cep47_hash.to_formatted_string(),
-            "token_standard" => standard
+            "contract_hashes" => contract_hashes,
+            "token_ids" => token_ids,
+            "price" => price,
+            "duration_minutes" => duration_minutes
         },
     )
-    .with_block_time(blocktime).build()
+    .build()
 }
 
+pub fn build_create_bundle_by_hash_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    nft_hashes: Vec<ContractHash>,
+    token_ids: Vec<U256>,
+    token_hashes: Vec<Option<String>>,
+    price: U512,
+) -> ExecuteRequest {
+    let contract_hashes: Vec<String> = nft_hashes
+        .iter()
+        .map(|hash| hash.to_formatted_string())
+        .collect();
 
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "create_bundle",
+        runtime_args! {
+            "contract_hashes" => contract_hashes,
+            "token_ids" => token_ids,
+            "token_hashes" => token_hashes,
+            "price" => price,
+            "duration_minutes" => 0u64
+        },
+    )
+    .build()
+}
 
-
-pub fn build_set_royalties_request(
+pub fn build_cancel_bundle_request(
     caller: AccountHash,
-    creator: AccountHash,
     marketplace_hash: ContractHash,
-    nft_hash: ContractHash,
-    percentage: u64
+    bundle_id: u64,
 ) -> ExecuteRequest {
-
     ExecuteRequestBuilder::contract_call_by_hash(
         caller,
         marketplace_hash,
-        "set_royalties",
+        "cancel_bundle",
         runtime_args! {
-            "contract_hash" => nft_hash.to_formatted_string(),
-            "royalties_percentage" => percentage,
-            "creator" => creator.to_formatted_string()
+            "bundle_id" => bundle_id
         },
     )
     .build()
 }
 
+pub fn build_get_bundle_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    bundle_id: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "get_bundle",
+        runtime_args! {
+            "bundle_id" => bundle_id
+        },
+    )
+    .build()
+}
+
+pub fn create_buy_bundle_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    bundle_id: u64,
+    amount_paid: U512,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        caller,
+        PAYMENT_WASM,
+        runtime_args! {
+            "bundle_id" => bundle_id,
+            "marketplace_hash" => marketplace_hash.to_formatted_string(),
+            "amount" => amount_paid
+        },
+    )
+    .build()
+}
+
+pub fn create_make_offer_request(
+    caller: AccountHash,
+    token_id: U256,
+    marketplace_hash: ContractHash,
+    contract_hash: ContractHash,
+    offered_amount: u64,
+    duration_minutes: u64
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        caller,
+        OFFER_WASM,
+        runtime_args! {
+            "token_id" => token_id,
+            "offer_contract_hash" => contract_hash.to_formatted_string(),
+            "marketplace_hash" => marketplace_hash.to_formatted_string(),
+            "amount" => U512::from(offered_amount),
+            "duration_minutes" => duration_minutes
+        },
+    )
+    .build()
+}
+
+pub fn create_make_offer_with_purse_amount_request(
+    caller: AccountHash,
+    token_id: U256,
+    marketplace_hash: ContractHash,
+    contract_hash: ContractHash,
+    offered_amount: U512,
+    purse_amount: U512,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        caller,
+        OFFER_WASM,
+        runtime_args! {
+            "token_id" => token_id,
+            "offer_contract_hash" => contract_hash.to_formatted_string(),
+            "marketplace_hash" => marketplace_hash.to_formatted_string(),
+            "amount" => purse_amount,
+            "trade_amount" => offered_amount,
+            "duration_minutes" => 60u64
+        },
+    )
+    .build()
+}
+
+pub fn create_make_offer_with_payment_token_request(
+    caller: AccountHash,
+    token_id: U256,
+    marketplace_hash: ContractHash,
+    contract_hash: ContractHash,
+    offered_amount: U512,
+    duration_minutes: u64,
+    payment_token: ContractHash,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        caller,
+        OFFER_WASM,
+        runtime_args! {
+            "token_id" => token_id,
+            "offer_contract_hash" => contract_hash.to_formatted_string(),
+            "marketplace_hash" => marketplace_hash.to_formatted_string(),
+            "amount" => offered_amount,
+            "duration_minutes" => duration_minutes,
+            "payment_token" => payment_token.to_formatted_string()
+        },
+    )
+    .build()
+}
+
+pub fn build_accept_offer_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    cep47_hash: ContractHash,
+    token_id: U256,
+    from: AccountHash,
+    amount: Option<U512>,
+    payment_token: Option<ContractHash>,
+    token_standard: u8,
+    blocktime: u64
+) -> ExecuteRequest {
+    let mut args = runtime_args! {
+        "token_id" => token_id,
+        "contract_hash" => cep47_hash.to_formatted_string(),
+        "offerer" => from.to_formatted_string(),
+        "token_standard" => token_standard
+    };
+    if let Some(amount) = amount {
+        args.insert("amount", amount).unwrap();
+    }
+    if let Some(payment_token) = payment_token {
+        args.insert("payment_token", payment_token.to_formatted_string())
+            .unwrap();
+    }
+
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        ENTRY_POINT_ACCEPT_OFFER,
+        args,
+    )
+    .with_block_time(blocktime).build()
+}
+
+pub fn build_cancel_offer_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    cep47_hash: ContractHash,
+    token_id: U256,
+) -> ExecuteRequest {
+
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        ENTRY_POINT_CANCEL_OFFER,
+        runtime_args! {
+            "token_id" => token_id,
+            "contract_hash" => cep47_hash.to_formatted_string(),
+        },
+    )
+    .build()
+}
+
+pub fn build_cancel_listing_request(
+    
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    cep47_hash: ContractHash,
+    token_id: U256,
+    standard: u8
+) -> ExecuteRequest {
+
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "cancel_listing",
+        runtime_args! {
+            "token_id" => token_id,
+            "contract_hash" => cep47_hash.to_formatted_string(),
+            "token_standard" => standard
+        },
+    )
+    .build()
+}
+
+pub fn build_start_auction_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    cep47_hash: ContractHash,
+    token_id: U256,
+    standard: u8
+) -> ExecuteRequest {
+
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "start_auction",
+        runtime_args! {
+            "token_id" => token_id,
+            "contract_hash" => cep47_hash.to_formatted_string(),
+            "duration_minutes" => 20u64,
+            "price" => U512::from(10),
+            "token_standard" => standard
+        },
+    )
+    .build()
+}
+
+
+
+
+/// Starts auction with optional auction settings passed as extra args
+pub fn build_start_auction_with_args_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    nft_hash: ContractHash,
+    token_id: U256,
+    standard: u8,
+    starting_price: U512,
+    extra_args: RuntimeArgs,
+    blocktime: u64,
+) -> ExecuteRequest {
+    let mut args = runtime_args! {
+        "token_id" => token_id,
+        "contract_hash" => nft_hash.to_formatted_string(),
+        "duration_minutes" => 20u64,
+        "price" => starting_price,
+        "token_standard" => standard
+    };
+    for named_arg in extra_args.named_args() {
+        args.insert_cl_value(named_arg.name(), named_arg.cl_value().clone());
+    }
+
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "start_auction",
+        args,
+    )
+    .with_block_time(blocktime)
+    .build()
+}
+
+pub fn build_set_auction_extension_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    window_minutes: u64,
+    extension_minutes: u64,
+    max_extensions: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "set_auction_extension",
+        runtime_args! {
+            "extension_window_minutes" => window_minutes,
+            "extension_minutes" => extension_minutes,
+            "max_extensions" => max_extensions
+        },
+    )
+    .build()
+}
+
+pub fn create_place_bid_request(
+    caller: AccountHash,
+    token_id: U256,
+    bid: U512,
+    marketplace_hash: ContractHash,
+    contract_hash: ContractHash,
+    blocktime: u64
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        caller,
+        BID_WASM,
+        runtime_args! {
+            "token_id" => token_id,
+            "bid_contract_hash" => contract_hash.to_formatted_string(),
+            "marketplace_hash" => marketplace_hash.to_formatted_string(),
+            "amount" => bid
+        },
+    )
+    .with_block_time(blocktime).build()
+}
+
+
+pub fn create_place_bid_with_purse_amount_request(
+    caller: AccountHash,
+    token_id: U256,
+    bid: U512,
+    purse_amount: U512,
+    marketplace_hash: ContractHash,
+    contract_hash: ContractHash,
+    blocktime: u64
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        caller,
+        BID_WASM,
+        runtime_args! {
+            "token_id" => token_id,
+            "bid_contract_hash" => contract_hash.to_formatted_string(),
+            "marketplace_hash" => marketplace_hash.to_formatted_string(),
+            "amount" => purse_amount,
+            "trade_amount" => bid
+        },
+    )
+    .with_block_time(blocktime).build()
+}
+
+
+pub fn create_buy_now_request(
+    caller: AccountHash,
+    token_id: U256,
+    amount: U512,
+    marketplace_hash: ContractHash,
+    nft_hash: ContractHash,
+    blocktime: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        caller,
+        BID_WASM,
+        runtime_args! {
+            "token_id" => token_id,
+            "bid_contract_hash" => nft_hash.to_formatted_string(),
+            "marketplace_hash" => marketplace_hash.to_formatted_string(),
+            "amount" => amount,
+            "buy_now" => true
+        },
+    )
+    .with_block_time(blocktime)
+    .build()
+}
+
+pub fn build_end_auction_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    cep47_hash: ContractHash,
+    token_id: U256,
+    blocktime: u64,
+    standard: u8
+
+) -> ExecuteRequest {
+
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "end_auction",
+        runtime_args! {
+            "token_id" => token_id,
+            "contract_hash" => cep47_hash.to_formatted_string(),
+            "token_standard" => standard
+        },
+    )
+    .with_block_time(blocktime).build()
+}
+
+/// Ends auction revealing reserve seller committed to at start
+pub fn build_end_auction_with_reveal_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    nft_hash: ContractHash,
+    token_id: U256,
+    reserve_price: U512,
+    reserve_salt: &str,
+    blocktime: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "end_auction",
+        runtime_args! {
+            "token_id" => token_id,
+            "contract_hash" => nft_hash.to_formatted_string(),
+            "reserve_price" => reserve_price,
+            "reserve_salt" => reserve_salt.to_string()
+        },
+    )
+    .with_block_time(blocktime)
+    .build()
+}
+
+pub fn build_start_dutch_auction_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    nft_hash: ContractHash,
+    token_id: U256,
+    standard: u8,
+    start_price: U512,
+    floor_price: U512,
+    duration_minutes: u64,
+    step_minutes: u64,
+    blocktime: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "start_dutch_auction",
+        runtime_args! {
+            "token_id" => token_id,
+            "contract_hash" => nft_hash.to_formatted_string(),
+            "price" => start_price,
+            "floor_price" => floor_price,
+            "duration_minutes" => duration_minutes,
+            "step_minutes" => step_minutes,
+            "token_standard" => standard
+        },
+    )
+    .with_block_time(blocktime)
+    .build()
+}
+
+pub fn create_buy_dutch_auction_request(
+    caller: AccountHash,
+    token_id: U256,
+    amount: U512,
+    marketplace_hash: ContractHash,
+    nft_hash: ContractHash,
+    blocktime: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        caller,
+        BID_WASM,
+        runtime_args! {
+            "token_id" => token_id,
+            "bid_contract_hash" => nft_hash.to_formatted_string(),
+            "marketplace_hash" => marketplace_hash.to_formatted_string(),
+            "amount" => amount,
+            "dutch_auction" => true
+        },
+    )
+    .with_block_time(blocktime)
+    .build()
+}
+
+pub fn build_cancel_dutch_auction_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    nft_hash: ContractHash,
+    token_id: U256,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "cancel_dutch_auction",
+        runtime_args! {
+            "token_id" => token_id,
+            "contract_hash" => nft_hash.to_formatted_string()
+        },
+    )
+    .build()
+}
+
+pub fn build_get_current_price_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    nft_hash: ContractHash,
+    token_id: U256,
+    blocktime: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "get_current_price",
+        runtime_args! {
+            "token_id" => token_id,
+            "contract_hash" => nft_hash.to_formatted_string()
+        },
+    )
+    .with_block_time(blocktime)
+    .build()
+}
+
+
+
+
+pub fn build_set_royalties_request(
+    caller: AccountHash,
+    creator: AccountHash,
+    marketplace_hash: ContractHash,
+    nft_hash: ContractHash,
+    percentage: u64
+) -> ExecuteRequest {
+
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "set_royalties",
+        runtime_args! {
+            "contract_hash" => nft_hash.to_formatted_string(),
+            "royalties_percentage" => percentage,
+            "creator" => creator.to_formatted_string()
+        },
+    )
+    .build()
+}
+
+
+pub fn build_deposit_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    amount: U512,
+) -> ExecuteRequest {
+
+    ExecuteRequestBuilder::standard(
+        caller,
+        PAYMENT_WASM,
+        runtime_args! {
+            "marketplace_hash" => marketplace_hash.to_formatted_string(),
+            "amount" => amount,
+            "deposit" => true
+        },
+    )
+    .build()
+}
+
+pub fn build_withdraw_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    amount: Option<U512>,
+) -> ExecuteRequest {
+
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "withdraw",
+        runtime_args! {
+            "amount" => amount
+        },
+    )
+    .build()
+}
+
+pub fn build_withdraw_token_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    payment_token: ContractHash,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "withdraw",
+        runtime_args! {
+            "amount" => Option::<U512>::None,
+            "payment_token" => payment_token.to_formatted_string()
+        },
+    )
+    .build()
+}
+
+pub fn build_get_pending_balance_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    account: Key,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "get_pending_balance",
+        runtime_args! {
+            "account" => account
+        },
+    )
+    .build()
+}
+
+pub fn build_set_marketplace_fee_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    fee_basis_points: u64,
+) -> ExecuteRequest {
+
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "set_marketplace_fee",
+        runtime_args! {
+            "fee_basis_points" => fee_basis_points
+        },
+    )
+    .build()
+}
+
+pub fn build_set_fee_recipient_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    fee_recipient: Option<Key>,
+) -> ExecuteRequest {
+
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "set_fee_recipient",
+        runtime_args! {
+            "fee_recipient" => fee_recipient
+        },
+    )
+    .build()
+}
+
+pub fn build_set_collection_fee_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    nft_hash: ContractHash,
+    fee_basis_points: Option<u64>,
+) -> ExecuteRequest {
+
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "set_collection_fee",
+        runtime_args! {
+            "contract_hash" => nft_hash.to_formatted_string(),
+            "fee_basis_points" => fee_basis_points
+        },
+    )
+    .build()
+}
+
+pub fn build_register_collection_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    nft_hash: ContractHash,
+    token_standard: u8,
+    identifier_mode: u8,
+) -> ExecuteRequest {
+
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "register_collection",
+        runtime_args! {
+            "contract_hash" => nft_hash.to_formatted_string(),
+            "token_standard" => token_standard,
+            "identifier_mode" => identifier_mode
+        },
+    )
+    .build()
+}
+
+pub fn build_unverify_collection_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    nft_hash: ContractHash,
+    token_standard: u8,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "register_collection",
+        runtime_args! {
+            "contract_hash" => nft_hash.to_formatted_string(),
+            "token_standard" => token_standard,
+            "identifier_mode" => 0u8,
+            "verified" => false
+        },
+    )
+    .build()
+}
+
+pub fn build_set_curated_mode_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    curated: bool,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "set_curated_mode",
+        runtime_args! {
+            "curated" => curated
+        },
+    )
+    .build()
+}
+
+pub fn build_get_listing_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    nft_hash: ContractHash,
+    token_id: U256,
+) -> ExecuteRequest {
+
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "get_listing",
+        runtime_args! {
+            "contract_hash" => nft_hash.to_formatted_string(),
+            "token_id" => token_id
+        },
+    )
+    .build()
+}
+
+/// Calls getter from session, which stores returned fields under caller's named key
+pub fn build_read_getter_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    entry_point: &str,
+    nft_hash: ContractHash,
+    token_id: U256,
+    offerer: Option<AccountHash>,
+) -> ExecuteRequest {
+    let mut args = runtime_args! {
+        "marketplace_hash" => marketplace_hash.to_formatted_string(),
+        "entry_point" => entry_point,
+        "contract_hash" => nft_hash.to_formatted_string(),
+        "token_id" => token_id
+    };
+    if let Some(offerer) = offerer {
+        args.insert("offerer", offerer.to_formatted_string()).unwrap();
+    }
+
+    ExecuteRequestBuilder::standard(caller, GETTER_WASM, args).build()
+}
+
+pub fn build_get_offer_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    nft_hash: ContractHash,
+    token_id: U256,
+    offerer: AccountHash,
+) -> ExecuteRequest {
+
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "get_offer",
+        runtime_args! {
+            "contract_hash" => nft_hash.to_formatted_string(),
+            "token_id" => token_id,
+            "offerer" => offerer.to_formatted_string()
+        },
+    )
+    .build()
+}
+
+pub fn build_get_auction_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    nft_hash: ContractHash,
+    token_id: U256,
+) -> ExecuteRequest {
+
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "get_auction",
+        runtime_args! {
+            "contract_hash" => nft_hash.to_formatted_string(),
+            "token_id" => token_id
+        },
+    )
+    .build()
+}
+
+pub fn build_get_royalty_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    nft_hash: ContractHash,
+) -> ExecuteRequest {
+
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "get_royalty",
+        runtime_args! {
+            "contract_hash" => nft_hash.to_formatted_string()
+        },
+    )
+    .build()
+}
+
+pub fn build_get_token_metadata_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    nft_hash: ContractHash,
+    token_id: U256,
+) -> ExecuteRequest {
+
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "get_token_metadata",
+        runtime_args! {
+            "contract_hash" => nft_hash.to_formatted_string(),
+            "token_id" => token_id
+        },
+    )
+    .build()
+}
+
+pub fn build_get_marketplace_fee_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+) -> ExecuteRequest {
+
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "get_marketplace_fee",
+        runtime_args! {},
+    )
+    .build()
+}
+
+pub fn build_block_token_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    nft_hash: ContractHash,
+    token_id: U256,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "block_token",
+        runtime_args! {
+            "contract_hash" => nft_hash.to_formatted_string(),
+            "token_id" => token_id
+        },
+    )
+    .build()
+}
+
+pub fn build_unblock_token_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    nft_hash: ContractHash,
+    token_id: U256,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "unblock_token",
+        runtime_args! {
+            "contract_hash" => nft_hash.to_formatted_string(),
+            "token_id" => token_id
+        },
+    )
+    .build()
+}
+
+pub fn build_block_account_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    account: AccountHash,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "block_account",
+        runtime_args! {
+            "account" => Key::Account(account)
+        },
+    )
+    .build()
+}
+
+pub fn build_unblock_account_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    account: AccountHash,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "unblock_account",
+        runtime_args! {
+            "account" => Key::Account(account)
+        },
+    )
+    .build()
+}
+
+pub fn build_force_delist_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    nft_hash: ContractHash,
+    token_id: U256,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "force_delist",
+        runtime_args! {
+            "contract_hash" => nft_hash.to_formatted_string(),
+            "token_id" => token_id
+        },
+    )
+    .build()
+}
+
+pub fn build_grant_role_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    role: u8,
+    account: AccountHash,
+) -> ExecuteRequest {
+
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "grant_role",
+        runtime_args! {
+            "role" => role,
+            "account" => Key::Account(account)
+        },
+    )
+    .build()
+}
+
+pub fn build_revoke_role_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    role: u8,
+    account: AccountHash,
+) -> ExecuteRequest {
+
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "revoke_role",
+        runtime_args! {
+            "role" => role,
+            "account" => Key::Account(account)
+        },
+    )
+    .build()
+}
+
+pub fn build_propose_admin_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    new_admin: AccountHash,
+) -> ExecuteRequest {
+
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "propose_admin",
+        runtime_args! {
+            "new_admin" => Key::Account(new_admin)
+        },
+    )
+    .build()
+}
+
+pub fn build_accept_admin_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+) -> ExecuteRequest {
+
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "accept_admin",
+        runtime_args! {},
+    )
+    .build()
+}
+
+pub fn build_cancel_admin_proposal_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+) -> ExecuteRequest {
+
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        "cancel_admin_proposal",
+        runtime_args! {},
+    )
+    .build()
+}
+
+pub fn build_pause_request(
+    caller: AccountHash,
+    marketplace_hash: ContractHash,
+    features: u8,
+    paused: bool,
+) -> ExecuteRequest {
+
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        marketplace_hash,
+        if paused { "pause" } else { "unpause" },
+        runtime_args! {
+            "features" => features
+        },
+    )
+    .build()
+}
@@ -1,6 +1,7 @@
 pub(crate) mod cep47_helpers;
 pub(crate) mod fixtures;
 pub(crate) mod cep78_helpers;
+pub(crate) mod cep18_helpers;
 pub(crate) mod marketplace_actions;
 pub(crate) mod constants;
 pub(crate) mod blockchain_helpers;
\ No newline at end of file
@@ -4,6 +4,42 @@ mod listings;
 #[cfg(test)]
 mod offers;
 
+#[cfg(test)]
+mod cep18;
+
+#[cfg(test)]
+mod collections;
+
+#[cfg(test)]
+mod blocklist;
+
+#[cfg(test)]
+mod bundles;
+
+#[cfg(test)]
+mod auctions;
+
+#[cfg(test)]
+mod fees;
+
+#[cfg(test)]
+mod roles;
+
+#[cfg(test)]
+mod admin;
+
+#[cfg(test)]
+mod getters;
+
+#[cfg(test)]
+mod pause;
+
+#[cfg(test)]
+mod upgrade;
+
+#[cfg(test)]
+mod withdrawals;
+
 
 #[cfg(test)]
 mod helpers;
\ No newline at end of file
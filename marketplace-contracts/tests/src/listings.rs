@@ -1,15 +1,26 @@
 use casper_types::{U256, U512};
 
 use crate::helpers::{
-    blockchain_helpers::{get_account_balance, get_user},
+    blockchain_helpers::{get_account_balance, get_pending_balance, get_user},
     cep47_helpers::approve_cep_47,
-    cep78_helpers::approve_cep_78,
+    cep78_helpers::{
+        approve_all_cep_78, approve_cep_78, approve_cep_78_by_hash, deploy_cep_78_hash_mode,
+        get_cep_78_token_hash, mint_cep_78,
+    },
     constants::MARKETPLACE_FEE_PERCENTAGE,
     fixtures::{
         get_default_fixture, get_listing_created_fixture,
         get_listing_created_fixture_with_royalties,
     },
-    marketplace_actions::{build_cancel_listing_request, create_buy_nft_request, create_listing},
+    marketplace_actions::{
+        build_cancel_listing_request, build_cancel_listings_batch_by_hash_request,
+        build_cancel_listings_batch_request, build_create_listings_batch_by_hash_request,
+        build_create_listings_batch_request,
+        build_withdraw_request, create_buy_listings_batch_by_hash_request,
+        create_buy_listings_batch_request, create_buy_nft_by_hash_request,
+        create_buy_nft_request, create_buy_nft_with_purse_amount_request, create_listing,
+        create_listing_by_hash, create_scheduled_listing,
+    },
 };
 
 #[test]
@@ -394,8 +405,9 @@ fn should_buy_listing_if_time_and_price_ok() {
     let seller = get_user(&mut builder, 2);
     let marketplace = get_user(&mut builder, 0);
 
-    let balance_marketplace_creator_before = get_account_balance(&mut builder, marketplace);
-    let balance_seller_before = get_account_balance(&mut builder, seller);
+    let balance_marketplace_creator_before =
+        get_pending_balance(&mut builder, marketplace_hash, marketplace);
+    let balance_seller_before = get_pending_balance(&mut builder, marketplace_hash, seller);
 
     // For CEP47
     let req = create_buy_nft_request(
@@ -409,8 +421,9 @@ fn should_buy_listing_if_time_and_price_ok() {
     );
     builder.exec(req).expect_success().commit();
 
-    let balance_marketplace_creator_after = get_account_balance(&mut builder, marketplace);
-    let balance_seller_after = get_account_balance(&mut builder, seller);
+    let balance_marketplace_creator_after =
+        get_pending_balance(&mut builder, marketplace_hash, marketplace);
+    let balance_seller_after = get_pending_balance(&mut builder, marketplace_hash, seller);
 
     let expected_marketplace_revenue =
         listing_price * U512::from(MARKETPLACE_FEE_PERCENTAGE) / U512::from(100);
@@ -425,8 +438,9 @@ fn should_buy_listing_if_time_and_price_ok() {
         balance_seller_after - balance_seller_before
     );
 
-    let balance_marketplace_creator_before = get_account_balance(&mut builder, marketplace);
-    let balance_seller_before = get_account_balance(&mut builder, seller);
+    let balance_marketplace_creator_before =
+        get_pending_balance(&mut builder, marketplace_hash, marketplace);
+    let balance_seller_before = get_pending_balance(&mut builder, marketplace_hash, seller);
 
     // For CEP47
     let req = create_buy_nft_request(
@@ -440,8 +454,9 @@ fn should_buy_listing_if_time_and_price_ok() {
     );
     builder.exec(req).expect_success().commit();
 
-    let balance_marketplace_creator_after = get_account_balance(&mut builder, marketplace);
-    let balance_seller_after = get_account_balance(&mut builder, seller);
+    let balance_marketplace_creator_after =
+        get_pending_balance(&mut builder, marketplace_hash, marketplace);
+    let balance_seller_after = get_pending_balance(&mut builder, marketplace_hash, seller);
 
     let expected_marketplace_revenue =
         listing_price * U512::from(MARKETPLACE_FEE_PERCENTAGE) / U512::from(100);
@@ -478,9 +493,11 @@ fn should_buy_listing_with_royalties() {
     let seller = get_user(&mut builder, 2);
     let marketplace = get_user(&mut builder, 0);
 
-    let balance_marketplace_creator_before = get_account_balance(&mut builder, marketplace);
-    let balance_seller_before = get_account_balance(&mut builder, seller);
-    let balance_creator_before = get_account_balance(&mut builder, royalty_creator);
+    let balance_marketplace_creator_before =
+        get_pending_balance(&mut builder, marketplace_hash, marketplace);
+    let balance_seller_before = get_pending_balance(&mut builder, marketplace_hash, seller);
+    let balance_creator_before =
+        get_pending_balance(&mut builder, marketplace_hash, royalty_creator);
 
     // For CEP47
     let req = create_buy_nft_request(
@@ -494,9 +511,11 @@ fn should_buy_listing_with_royalties() {
     );
     builder.exec(req).expect_success().commit();
 
-    let balance_marketplace_creator_after = get_account_balance(&mut builder, marketplace);
-    let balance_seller_after = get_account_balance(&mut builder, seller);
-    let balance_creator_after: U512 = get_account_balance(&mut builder, royalty_creator);
+    let balance_marketplace_creator_after =
+        get_pending_balance(&mut builder, marketplace_hash, marketplace);
+    let balance_seller_after = get_pending_balance(&mut builder, marketplace_hash, seller);
+    let balance_creator_after: U512 =
+        get_pending_balance(&mut builder, marketplace_hash, royalty_creator);
 
     let expected_marketplace_revenue =
         listing_price * U512::from(MARKETPLACE_FEE_PERCENTAGE) / U512::from(100);
@@ -504,9 +523,11 @@ fn should_buy_listing_with_royalties() {
   
 
 
-    let balance_marketplace_creator_before = get_account_balance(&mut builder, marketplace);
-    let balance_seller_before = get_account_balance(&mut builder, seller);
-    let balance_creator_before = get_account_balance(&mut builder, royalty_creator);
+    let balance_marketplace_creator_before =
+        get_pending_balance(&mut builder, marketplace_hash, marketplace);
+    let balance_seller_before = get_pending_balance(&mut builder, marketplace_hash, seller);
+    let balance_creator_before =
+        get_pending_balance(&mut builder, marketplace_hash, royalty_creator);
 
     // For CEP47
     let req = create_buy_nft_request(
@@ -520,9 +541,11 @@ fn should_buy_listing_with_royalties() {
     );
     builder.exec(req).expect_success().commit();
 
-    let balance_marketplace_creator_after = get_account_balance(&mut builder, marketplace);
-    let balance_seller_after = get_account_balance(&mut builder, seller);
-    let balance_creator_after: U512 = get_account_balance(&mut builder, royalty_creator);
+    let balance_marketplace_creator_after =
+        get_pending_balance(&mut builder, marketplace_hash, marketplace);
+    let balance_seller_after = get_pending_balance(&mut builder, marketplace_hash, seller);
+    let balance_creator_after: U512 =
+        get_pending_balance(&mut builder, marketplace_hash, royalty_creator);
 
     let expected_marketplace_revenue =
         listing_price * U512::from(MARKETPLACE_FEE_PERCENTAGE) / U512::from(100);
@@ -532,3 +555,544 @@ fn should_buy_listing_with_royalties() {
 
 
 }
+
+#[test]
+fn should_revert_buy_listing_if_amount_differs_from_price() {
+    let (
+        mut builder,
+        marketplace_hash,
+        cep47_hash,
+        _cep78_hash,
+        _marketplace_package_hash,
+        listing_price,
+        _listing_duration_minutes,
+    ) = get_listing_created_fixture();
+
+    let buyer = get_user(&mut builder, 1);
+    let overpayment = U512::from(500_000_000u64);
+
+    // For CEP47
+    let req = create_buy_nft_request(
+        buyer,
+        U256::from(1),
+        marketplace_hash,
+        cep47_hash,
+        (listing_price + overpayment).as_u64(),
+        0,
+        0,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let req = create_buy_nft_request(
+        buyer,
+        U256::from(1),
+        marketplace_hash,
+        cep47_hash,
+        listing_price.as_u64(),
+        0,
+        0,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Exact payment leaves nothing in buyer escrow
+    let req = build_withdraw_request(buyer, marketplace_hash, Some(U512::one()));
+    builder.exec(req).expect_failure().commit();
+}
+
+#[test]
+fn should_refund_overpayment_only_to_buyer() {
+    let (
+        mut builder,
+        marketplace_hash,
+        cep47_hash,
+        _cep78_hash,
+        _marketplace_package_hash,
+        listing_price,
+        _listing_duration_minutes,
+    ) = get_listing_created_fixture();
+
+    let buyer = get_user(&mut builder, 1);
+    let other_user = get_user(&mut builder, 3);
+    let overpayment = U512::from(500_000_000u64);
+
+    let balance_buyer_before = get_account_balance(&mut builder, buyer);
+    let balance_other_before = get_account_balance(&mut builder, other_user);
+
+    // Purse holds more than price, amount names what is paid
+    let req = create_buy_nft_with_purse_amount_request(
+        buyer,
+        U256::from(1),
+        marketplace_hash,
+        cep47_hash,
+        listing_price,
+        listing_price + overpayment,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Leftover goes back to buyer right away
+    assert_eq!(
+        balance_buyer_before - get_account_balance(&mut builder, buyer),
+        listing_price
+    );
+    assert_eq!(get_account_balance(&mut builder, other_user), balance_other_before);
+
+    // Nothing stays in escrow for anyone to withdraw
+    let req = build_withdraw_request(other_user, marketplace_hash, Some(overpayment));
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_withdraw_request(buyer, marketplace_hash, Some(U512::one()));
+    builder.exec(req).expect_failure().commit();
+}
+
+#[test]
+fn should_list_and_buy_cep78_token_by_hash() {
+    let (mut builder, market_hash, _cep47_hash, _cep78_hash, _market_package_hash) =
+        get_default_fixture();
+
+    let buyer = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let listing_price = U512::from(1_000_000_000_000u64);
+
+    let cep78_hash = deploy_cep_78_hash_mode(&mut builder);
+    mint_cep_78(&mut builder, cep78_hash, seller.into(), vec![]);
+    let token_hash = get_cep_78_token_hash("{\"name\":\"essa\"}");
+
+    approve_cep_78_by_hash(
+        &mut builder,
+        seller,
+        cep78_hash,
+        market_hash.into(),
+        token_hash.clone(),
+    );
+
+    // Numeric id is rejected for hash identified collection
+    let req = create_listing(seller, market_hash, cep78_hash, 0, listing_price, 0, 1);
+    builder.exec(req).expect_failure().commit();
+
+    let req = create_listing_by_hash(
+        seller,
+        market_hash,
+        cep78_hash,
+        token_hash.clone(),
+        listing_price,
+        0,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let balance_seller_before = get_pending_balance(&mut builder, market_hash, seller);
+
+    let req = create_buy_nft_by_hash_request(
+        buyer,
+        token_hash,
+        market_hash,
+        cep78_hash,
+        listing_price.as_u64(),
+    );
+    builder.exec(req).expect_success().commit();
+
+    let balance_seller_after = get_pending_balance(&mut builder, market_hash, seller);
+    assert_eq!(
+        listing_price * (100 - MARKETPLACE_FEE_PERCENTAGE) / 100,
+        balance_seller_after - balance_seller_before
+    );
+}
+
+#[test]
+fn should_let_create_cep78_listing_with_operator_approval() {
+    let (mut builder, market_hash, _cep47_hash, cep78_hash, _market_package_hash) =
+        get_default_fixture();
+
+    let buyer = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let listing_price = U512::from(1_000_000_000_000u64);
+
+    // Second token isn't approved on its own
+    mint_cep_78(&mut builder, cep78_hash, seller.into(), vec![]);
+    approve_all_cep_78(&mut builder, seller, cep78_hash, market_hash.into());
+
+    for token_id in 0..2 {
+        let req = create_listing(seller, market_hash, cep78_hash, token_id, listing_price, 0, 1);
+        builder.exec(req).expect_success().commit();
+    }
+
+    let req = create_buy_nft_request(
+        buyer,
+        U256::from(1),
+        market_hash,
+        cep78_hash,
+        listing_price.as_u64(),
+        1,
+        0,
+    );
+    builder.exec(req).expect_success().commit();
+}
+
+#[test]
+fn should_create_and_cancel_listings_in_batch() {
+    let (mut builder, market_hash, cep47_hash, cep78_hash, market_package_hash) =
+        get_default_fixture();
+
+    let buyer = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let price = U512::from(100u64);
+
+    approve_cep_47(
+        &mut builder,
+        seller,
+        cep47_hash,
+        market_package_hash.into(),
+        vec![U256::from(1)],
+    );
+
+    // Every item is checked, one missing approval reverts whole batch
+    let req = build_create_listings_batch_request(
+        seller,
+        market_hash,
+        vec![cep47_hash, cep78_hash],
+        vec![U256::from(1), U256::from(0)],
+        vec![price, price],
+        vec![0, 0],
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let req = create_buy_nft_request(buyer, U256::from(1), market_hash, cep47_hash, 100, 0, 0);
+    builder.exec(req).expect_failure().commit();
+
+    approve_cep_78(&mut builder, seller, cep78_hash, market_hash.into(), 0);
+
+    // Vectors must have equal length
+    let req = build_create_listings_batch_request(
+        seller,
+        market_hash,
+        vec![cep47_hash, cep78_hash],
+        vec![U256::from(1), U256::from(0)],
+        vec![price],
+        vec![0, 0],
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_create_listings_batch_request(
+        seller,
+        market_hash,
+        vec![cep47_hash, cep78_hash],
+        vec![U256::from(1), U256::from(0)],
+        vec![price, price],
+        vec![0, 0],
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Only owner can cancel
+    let req = build_cancel_listings_batch_request(
+        buyer,
+        market_hash,
+        vec![cep47_hash, cep78_hash],
+        vec![U256::from(1), U256::from(0)],
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_cancel_listings_batch_request(
+        seller,
+        market_hash,
+        vec![cep47_hash, cep78_hash],
+        vec![U256::from(1), U256::from(0)],
+    );
+    builder.exec(req).expect_success().commit();
+
+    let req = create_buy_nft_request(buyer, U256::from(1), market_hash, cep47_hash, 100, 0, 0);
+    builder.exec(req).expect_failure().commit();
+    let req = create_buy_nft_request(buyer, U256::from(0), market_hash, cep78_hash, 100, 1, 0);
+    builder.exec(req).expect_failure().commit();
+}
+
+#[test]
+fn should_batch_list_tokens_of_hash_identified_collection() {
+    let (mut builder, market_hash, cep47_hash, _cep78_hash, market_package_hash) =
+        get_default_fixture();
+
+    let buyer = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let price = U512::from(100u64);
+
+    let cep78_hash = deploy_cep_78_hash_mode(&mut builder);
+    mint_cep_78(&mut builder, cep78_hash, seller.into(), vec![]);
+    let token_hash = get_cep_78_token_hash("{\"name\":\"essa\"}");
+
+    approve_cep_47(
+        &mut builder,
+        seller,
+        cep47_hash,
+        market_package_hash.into(),
+        vec![U256::from(1)],
+    );
+    approve_cep_78_by_hash(
+        &mut builder,
+        seller,
+        cep78_hash,
+        market_hash.into(),
+        token_hash.clone(),
+    );
+
+    // Hash list must cover every item
+    let req = build_create_listings_batch_by_hash_request(
+        seller,
+        market_hash,
+        vec![cep47_hash, cep78_hash],
+        vec![U256::from(1), U256::zero()],
+        vec![None],
+        vec![price, price],
+    );
+    builder.exec(req).expect_failure().commit();
+
+    // Id of item passed by hash is ignored
+    let req = build_create_listings_batch_by_hash_request(
+        seller,
+        market_hash,
+        vec![cep47_hash, cep78_hash],
+        vec![U256::from(1), U256::zero()],
+        vec![None, Some(token_hash.clone())],
+        vec![price, price],
+    );
+    builder.exec(req).expect_success().commit();
+
+    let req = build_cancel_listings_batch_by_hash_request(
+        seller,
+        market_hash,
+        vec![cep78_hash],
+        vec![U256::zero()],
+        vec![Some(token_hash.clone())],
+    );
+    builder.exec(req).expect_success().commit();
+
+    let req = create_buy_nft_by_hash_request(buyer, token_hash, market_hash, cep78_hash, 100);
+    builder.exec(req).expect_failure().commit();
+    let req = create_buy_nft_request(buyer, U256::from(1), market_hash, cep47_hash, 100, 0, 0);
+    builder.exec(req).expect_success().commit();
+}
+
+#[test]
+fn should_sweep_tokens_of_hash_identified_collection() {
+    let (mut builder, market_hash, cep47_hash, _cep78_hash, market_package_hash) =
+        get_default_fixture();
+
+    let buyer = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let listing_price = U512::from(1_000_000_000_000u64);
+
+    let cep78_hash = deploy_cep_78_hash_mode(&mut builder);
+    mint_cep_78(&mut builder, cep78_hash, seller.into(), vec![]);
+    let token_hash = get_cep_78_token_hash("{\"name\":\"essa\"}");
+
+    approve_cep_47(
+        &mut builder,
+        seller,
+        cep47_hash,
+        market_package_hash.into(),
+        vec![U256::from(1)],
+    );
+    let req = create_listing(seller, market_hash, cep47_hash, 1, listing_price, 0, 0);
+    builder.exec(req).expect_success().commit();
+
+    approve_cep_78_by_hash(
+        &mut builder,
+        seller,
+        cep78_hash,
+        market_hash.into(),
+        token_hash.clone(),
+    );
+    let req = create_listing_by_hash(
+        seller,
+        market_hash,
+        cep78_hash,
+        token_hash.clone(),
+        listing_price,
+        0,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let balance_seller_before = get_pending_balance(&mut builder, market_hash, seller);
+
+    let req = create_buy_listings_batch_by_hash_request(
+        buyer,
+        market_hash,
+        vec![cep47_hash, cep78_hash],
+        vec![U256::from(1), U256::zero()],
+        vec![None, Some(token_hash)],
+        listing_price * 2,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let balance_seller_after = get_pending_balance(&mut builder, market_hash, seller);
+    assert_eq!(
+        listing_price * 2 * (100 - MARKETPLACE_FEE_PERCENTAGE) / 100,
+        balance_seller_after - balance_seller_before
+    );
+}
+
+#[test]
+fn should_sweep_listings_in_one_deploy() {
+    let (
+        mut builder,
+        marketplace_hash,
+        cep47_hash,
+        cep78_hash,
+        _marketplace_package_hash,
+        listing_price,
+        _listing_duration_minutes,
+    ) = get_listing_created_fixture();
+
+    let buyer = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+
+    // Token 2 is not listed
+    let nft_hashes = vec![cep47_hash, cep78_hash, cep47_hash];
+    let token_ids = vec![U256::from(1), U256::from(0), U256::from(2)];
+
+    // Atomic sweep reverts on unavailable item
+    let req = create_buy_listings_batch_request(
+        buyer,
+        marketplace_hash,
+        nft_hashes.clone(),
+        token_ids.clone(),
+        listing_price * 3,
+        false,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    // Funds must cover every bought listing
+    let req = create_buy_listings_batch_request(
+        buyer,
+        marketplace_hash,
+        nft_hashes.clone(),
+        token_ids.clone(),
+        listing_price,
+        true,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let balance_buyer_before = get_account_balance(&mut builder, buyer);
+
+    let req = create_buy_listings_batch_request(
+        buyer,
+        marketplace_hash,
+        nft_hashes,
+        token_ids,
+        listing_price * 3,
+        true,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Price of skipped item is refunded
+    let paid = balance_buyer_before - get_account_balance(&mut builder, buyer);
+    assert!(paid >= listing_price * 2);
+    assert!(paid < listing_price * 3);
+    assert_eq!(
+        get_pending_balance(&mut builder, marketplace_hash, buyer),
+        U512::zero()
+    );
+    assert_eq!(
+        get_pending_balance(&mut builder, marketplace_hash, seller),
+        listing_price * 2 * (100 - MARKETPLACE_FEE_PERCENTAGE) / 100
+    );
+
+    // Bought listings are closed
+    let req = create_buy_nft_request(
+        buyer,
+        U256::from(1),
+        marketplace_hash,
+        cep47_hash,
+        listing_price.as_u64(),
+        0,
+        0,
+    );
+    builder.exec(req).expect_failure().commit();
+}
+
+#[test]
+fn should_skip_sweep_item_without_recording_collection() {
+    let (mut builder, marketplace_hash, _cep47_hash, cep78_hash, _marketplace_package_hash) =
+        get_default_fixture();
+
+    let buyer = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let price = U512::from(1_000_000_000_000u64);
+
+    // Collection was never seen, unlisted item is skipped
+    let req = create_buy_listings_batch_request(
+        buyer,
+        marketplace_hash,
+        vec![cep78_hash],
+        vec![U256::zero()],
+        price,
+        true,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Registry is untouched, so collection is still recorded with its real standard on listing
+    approve_cep_78(&mut builder, seller, cep78_hash, marketplace_hash.into(), 0);
+    let req = create_listing(seller, marketplace_hash, cep78_hash, 0, price, 0, 1);
+    builder.exec(req).expect_success().commit();
+
+    let req = create_buy_listings_batch_request(
+        buyer,
+        marketplace_hash,
+        vec![cep78_hash],
+        vec![U256::zero()],
+        price,
+        false,
+    );
+    builder.exec(req).expect_success().commit();
+}
+
+#[test]
+fn should_not_sell_scheduled_listing_before_start_time() {
+    let (mut builder, marketplace_hash, cep47_hash, _cep78_hash, marketplace_package_hash) =
+        get_default_fixture();
+
+    let buyer = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let price = U512::from(1_000_000_000_000u64);
+    let start_time = 600_000;
+
+    approve_cep_47(
+        &mut builder,
+        seller,
+        cep47_hash,
+        marketplace_package_hash.into(),
+        vec![U256::from(1)],
+    );
+
+    let req = create_scheduled_listing(
+        seller,
+        marketplace_hash,
+        cep47_hash,
+        1,
+        price,
+        10,
+        start_time,
+        0,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let req = create_buy_nft_request(
+        buyer,
+        U256::from(1),
+        marketplace_hash,
+        cep47_hash,
+        price.as_u64(),
+        0,
+        start_time - 60_000,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    // Duration counts from start time, so listing is still open
+    let req = create_buy_nft_request(
+        buyer,
+        U256::from(1),
+        marketplace_hash,
+        cep47_hash,
+        price.as_u64(),
+        0,
+        start_time + 9 * 60_000,
+    );
+    builder.exec(req).expect_success().commit();
+}
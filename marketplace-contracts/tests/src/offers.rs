@@ -2,13 +2,14 @@ use casper_types::{account::AccountHash, PublicKey, SecretKey, U256, U512};
 use sha2::digest::consts::U2;
 
 use crate::helpers::{
-    blockchain_helpers::{get_account_balance, get_user},
+    blockchain_helpers::{get_account_balance, get_pending_balance, get_user},
     cep47_helpers::approve_cep_47,
     cep78_helpers::approve_cep_78,
     constants::MARKETPLACE_FEE_PERCENTAGE,
     fixtures::{get_default_fixture, get_offers_created_fixture},
     marketplace_actions::{
         build_accept_offer_request, build_cancel_offer_request, create_make_offer_request,
+        create_make_offer_with_purse_amount_request,
     },
 };
 
@@ -33,6 +34,34 @@ fn should_let_create_offer_and_take_money() {
     assert_eq!(U512::from(price * 2), amount_before - amount_after);
 }
 
+#[test]
+fn should_refund_offer_purse_above_offered_amount() {
+    let (mut builder, market_hash, cep47_hash, _cep78_hash, _market_package_hash) =
+        get_default_fixture();
+
+    let price = U512::from(1_000_000_000u64);
+    let offerer = get_user(&mut builder, 1);
+
+    let amount_before = get_account_balance(&mut builder, offerer);
+
+    let req = create_make_offer_with_purse_amount_request(
+        offerer,
+        U256::from(1),
+        market_hash,
+        cep47_hash,
+        price,
+        price * 3,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Offer locks only offered amount
+    assert_eq!(amount_before - get_account_balance(&mut builder, offerer), price);
+    assert_eq!(
+        get_pending_balance(&mut builder, market_hash, offerer),
+        U512::zero()
+    );
+}
+
 #[test]
 fn should_let_cancel_offer_and_create_new_one() {
     let (
@@ -106,8 +135,8 @@ fn should_let_accept_offer_if_time_ok() {
     );
     approve_cep_78(&mut builder, seller, cep78_hash, market_hash.into(), 0);
 
-    let balance_seller_before = get_account_balance(&mut builder, seller);
-    let balance_market_before = get_account_balance(&mut builder, market);
+    let balance_seller_before = get_pending_balance(&mut builder, market_hash, seller);
+    let balance_market_before = get_pending_balance(&mut builder, market_hash, market);
 
     let req = build_accept_offer_request(
         seller,
@@ -115,6 +144,8 @@ fn should_let_accept_offer_if_time_ok() {
         cep78_hash,
         U256::from(0),
         offerer,
+        None,
+        None,
         1,
         0,
     );
@@ -126,13 +157,15 @@ fn should_let_accept_offer_if_time_ok() {
         cep47_hash,
         U256::from(1),
         offerer,
+        None,
+        None,
         0,
         0,
     );
     builder.exec(req).expect_success().commit();
 
-    let balance_seller_after = get_account_balance(&mut builder, seller);
-    let balance_market_after = get_account_balance(&mut builder, market);
+    let balance_seller_after = get_pending_balance(&mut builder, market_hash, seller);
+    let balance_market_after = get_pending_balance(&mut builder, market_hash, market);
 
     let expected_market_revenue =
         (price + price) * U512::from(MARKETPLACE_FEE_PERCENTAGE) / U512::from(100);
@@ -177,6 +210,8 @@ fn should_revert_accept_offer_if_time_expired() {
         cep78_hash,
         U256::from(0),
         offerer,
+        None,
+        None,
         1,
         blocktime_in_minutes * 60000,
     );
@@ -188,6 +223,8 @@ fn should_revert_accept_offer_if_time_expired() {
         cep47_hash,
         U256::from(1),
         offerer,
+        None,
+        None,
         0,
         blocktime_in_minutes * 60000,
     );
@@ -0,0 +1,73 @@
+use casper_types::U256;
+
+use crate::helpers::{
+    blockchain_helpers::get_user,
+    constants::{FEATURE_LISTINGS, ROLE_PAUSER},
+    fixtures::get_listing_created_fixture,
+    marketplace_actions::{
+        build_cancel_listing_request, build_grant_role_request, build_pause_request,
+        create_buy_nft_request, create_make_offer_request,
+    },
+};
+
+#[test]
+fn should_block_paused_feature_but_allow_exits() {
+    let (
+        mut builder,
+        marketplace_hash,
+        cep47_hash,
+        cep78_hash,
+        _marketplace_package_hash,
+        listing_price,
+        _listing_duration_minutes,
+    ) = get_listing_created_fixture();
+
+    let installer = get_user(&mut builder, 0);
+    let buyer = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let pauser = get_user(&mut builder, 3);
+
+    // Only pausers can pause
+    let req = build_pause_request(pauser, marketplace_hash, FEATURE_LISTINGS, true);
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_grant_role_request(installer, marketplace_hash, ROLE_PAUSER, pauser);
+    builder.exec(req).expect_success().commit();
+
+    let req = build_pause_request(pauser, marketplace_hash, FEATURE_LISTINGS, true);
+    builder.exec(req).expect_success().commit();
+
+    // Listings can't be bought
+    let req = create_buy_nft_request(
+        buyer,
+        U256::from(1),
+        marketplace_hash,
+        cep47_hash,
+        listing_price.as_u64(),
+        0,
+        0,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    // Other features keep working
+    let req = create_make_offer_request(buyer, U256::from(1), marketplace_hash, cep47_hash, 100, 15);
+    builder.exec(req).expect_success().commit();
+
+    // Sellers can still exit
+    let req = build_cancel_listing_request(seller, marketplace_hash, cep78_hash, U256::from(0), 1);
+    builder.exec(req).expect_success().commit();
+
+    let req = build_pause_request(pauser, marketplace_hash, FEATURE_LISTINGS, false);
+    builder.exec(req).expect_success().commit();
+
+    let req = create_buy_nft_request(
+        buyer,
+        U256::from(1),
+        marketplace_hash,
+        cep47_hash,
+        listing_price.as_u64(),
+        0,
+        0,
+    );
+    builder.exec(req).expect_success().commit();
+}
@@ -0,0 +1,62 @@
+use crate::helpers::{
+    blockchain_helpers::get_user,
+    constants::{ROLE_ADMIN, ROLE_ROYALTY_MANAGER},
+    fixtures::get_default_fixture,
+    marketplace_actions::{
+        build_grant_role_request, build_revoke_role_request, build_set_marketplace_fee_request,
+        build_set_royalties_request,
+    },
+};
+
+#[test]
+fn should_let_only_admin_grant_roles() {
+    let (mut builder, market_hash, _cep47_hash, _cep78_hash, _market_package_hash) =
+        get_default_fixture();
+
+    let installer = get_user(&mut builder, 0);
+    let user = get_user(&mut builder, 1);
+    let other_user = get_user(&mut builder, 2);
+
+    let req = build_grant_role_request(user, market_hash, ROLE_ADMIN, user);
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_grant_role_request(installer, market_hash, ROLE_ADMIN, user);
+    builder.exec(req).expect_success().commit();
+
+    // Granted admin can manage roles too
+    let req = build_grant_role_request(user, market_hash, ROLE_ROYALTY_MANAGER, other_user);
+    builder.exec(req).expect_success().commit();
+
+    // Unknown role is rejected
+    let req = build_grant_role_request(installer, market_hash, 100, other_user);
+    builder.exec(req).expect_failure().commit();
+}
+
+#[test]
+fn should_let_set_royalties_only_with_royalty_manager_role() {
+    let (mut builder, market_hash, cep47_hash, _cep78_hash, _market_package_hash) =
+        get_default_fixture();
+
+    let installer = get_user(&mut builder, 0);
+    let manager = get_user(&mut builder, 1);
+    let creator = get_user(&mut builder, 2);
+
+    let req = build_set_royalties_request(manager, creator, market_hash, cep47_hash, 5);
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_grant_role_request(installer, market_hash, ROLE_ROYALTY_MANAGER, manager);
+    builder.exec(req).expect_success().commit();
+
+    let req = build_set_royalties_request(manager, creator, market_hash, cep47_hash, 5);
+    builder.exec(req).expect_success().commit();
+
+    // Royalty manager can't change fees
+    let req = build_set_marketplace_fee_request(manager, market_hash, 0);
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_revoke_role_request(installer, market_hash, ROLE_ROYALTY_MANAGER, manager);
+    builder.exec(req).expect_success().commit();
+
+    let req = build_set_royalties_request(manager, creator, market_hash, cep47_hash, 5);
+    builder.exec(req).expect_failure().commit();
+}
@@ -0,0 +1,363 @@
+use casper_engine_test_support::ExecuteRequestBuilder;
+use casper_types::{runtime_args, RuntimeArgs, U256, U512};
+
+use crate::helpers::{
+    blockchain_helpers::{get_pending_balance, get_user},
+    cep47_helpers::{approve_cep_47, mint_cep_47},
+    cep78_helpers::{approve_cep_78, mint_cep_78},
+    fixtures::{
+        build_upgrade_marketplace_request, get_default_fixture, get_listing_created_fixture,
+        get_v1_fixture, upgrade_marketplace,
+    },
+    marketplace_actions::{
+        build_accept_offer_request, build_cancel_dutch_auction_request, build_end_auction_request,
+        build_set_marketplace_fee_request, build_start_auction_request,
+        build_start_dutch_auction_request, create_buy_nft_request, create_listing,
+        create_make_offer_request, create_place_bid_request,
+    },
+};
+
+#[test]
+fn should_keep_listings_and_settings_after_upgrade() {
+    let (
+        mut builder,
+        marketplace_hash,
+        cep47_hash,
+        _cep78_hash,
+        _marketplace_package_hash,
+        listing_price,
+        _listing_duration_minutes,
+    ) = get_listing_created_fixture();
+
+    let installer = get_user(&mut builder, 0);
+    let buyer = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+
+    let req = build_set_marketplace_fee_request(installer, marketplace_hash, 0);
+    builder.exec(req).expect_success().commit();
+
+    let upgraded_marketplace_hash = upgrade_marketplace(&mut builder);
+    assert_ne!(marketplace_hash, upgraded_marketplace_hash);
+
+    // Previous version is disabled
+    let req = create_buy_nft_request(
+        buyer,
+        U256::from(1),
+        marketplace_hash,
+        cep47_hash,
+        listing_price.as_u64(),
+        0,
+        0,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let balance_seller_before =
+        get_pending_balance(&mut builder, upgraded_marketplace_hash, seller);
+
+    // Listing created before upgrade can be bought, fee setting is kept
+    let req = create_buy_nft_request(
+        buyer,
+        U256::from(1),
+        upgraded_marketplace_hash,
+        cep47_hash,
+        listing_price.as_u64(),
+        0,
+        0,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let balance_seller_after = get_pending_balance(&mut builder, upgraded_marketplace_hash, seller);
+    assert_eq!(listing_price, balance_seller_after - balance_seller_before);
+}
+
+#[test]
+fn should_hand_over_escrowed_cep78_tokens_on_upgrade() {
+    let (mut builder, marketplace_hash, _cep47_hash, cep78_hash, _marketplace_package_hash) =
+        get_default_fixture();
+
+    let bidder = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+
+    approve_cep_78(&mut builder, seller, cep78_hash, marketplace_hash.into(), 0);
+
+    let req = build_start_auction_request(seller, marketplace_hash, cep78_hash, U256::from(0), 1);
+    builder.exec(req).expect_success().commit();
+
+    let req = create_place_bid_request(
+        bidder,
+        U256::from(0),
+        U512::from(100),
+        marketplace_hash,
+        cep78_hash,
+        1_000,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // CEP-78 token held under previous contract hash moves to new version
+    let upgraded_marketplace_hash = upgrade_marketplace(&mut builder);
+    assert_ne!(marketplace_hash, upgraded_marketplace_hash);
+
+    // Only a version of marketplace package can take escrowed tokens over
+    let req = ExecuteRequestBuilder::contract_call_by_hash(
+        bidder,
+        upgraded_marketplace_hash,
+        "hand_over_escrowed_tokens",
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_end_auction_request(
+        seller,
+        upgraded_marketplace_hash,
+        cep78_hash,
+        U256::from(0),
+        20 * 60_000 + 1_000,
+        1,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Winner got token and can list it
+    approve_cep_78(
+        &mut builder,
+        bidder,
+        cep78_hash,
+        upgraded_marketplace_hash.into(),
+        0,
+    );
+    let req = create_listing(
+        bidder,
+        upgraded_marketplace_hash,
+        cep78_hash,
+        0,
+        U512::from(1_000),
+        0,
+        1,
+    );
+    builder.exec(req).expect_success().commit();
+}
+
+#[test]
+fn should_keep_entries_of_first_version_after_upgrade() {
+    let (mut builder, marketplace_hash, cep47_hash, cep78_hash, marketplace_package_hash) =
+        get_v1_fixture();
+
+    let installer = get_user(&mut builder, 0);
+    let buyer = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let offerer = get_user(&mut builder, 3);
+    let listing_price = U512::from(1_000_000_000_000u64);
+    let offer_amount = U512::from(500_000_000_000u64);
+    let bid = U512::from(100);
+
+    mint_cep_47(&mut builder, cep47_hash, seller.into(), vec![U256::from(2)]);
+    approve_cep_47(
+        &mut builder,
+        seller,
+        cep47_hash,
+        marketplace_package_hash.into(),
+        vec![U256::from(1), U256::from(2)],
+    );
+
+    // Listing, offer and auction stored by first version
+    let req = create_listing(seller, marketplace_hash, cep47_hash, 1, listing_price, 60, 0);
+    builder.exec(req).expect_success().commit();
+
+    let req = create_make_offer_request(
+        offerer,
+        U256::from(0),
+        marketplace_hash,
+        cep78_hash,
+        offer_amount.as_u64(),
+        60,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let req = build_start_auction_request(seller, marketplace_hash, cep47_hash, U256::from(2), 0);
+    builder.exec(req).expect_success().commit();
+
+    let req = create_place_bid_request(
+        buyer,
+        U256::from(2),
+        bid,
+        marketplace_hash,
+        cep47_hash,
+        1_000,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // First version holds CEP-78 tokens under its contract hash without counting them
+    mint_cep_78(&mut builder, cep78_hash, seller.into(), vec![]);
+    approve_cep_78(&mut builder, seller, cep78_hash, marketplace_hash.into(), 1);
+    let req = build_start_auction_request(seller, marketplace_hash, cep78_hash, U256::from(1), 1);
+    builder.exec(req).expect_success().commit();
+
+    // First version can't hand CEP-78 tokens over, so upgrade waits until it holds none
+    let req = build_upgrade_marketplace_request();
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_end_auction_request(
+        seller,
+        marketplace_hash,
+        cep78_hash,
+        U256::from(1),
+        1_000,
+        1,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let upgraded_marketplace_hash = upgrade_marketplace(&mut builder);
+    assert_ne!(marketplace_hash, upgraded_marketplace_hash);
+
+    let req = build_set_marketplace_fee_request(installer, upgraded_marketplace_hash, 0);
+    builder.exec(req).expect_success().commit();
+
+    let balance_seller_before =
+        get_pending_balance(&mut builder, upgraded_marketplace_hash, seller);
+
+    let req = create_buy_nft_request(
+        buyer,
+        U256::from(1),
+        upgraded_marketplace_hash,
+        cep47_hash,
+        listing_price.as_u64(),
+        0,
+        2_000,
+    );
+    builder.exec(req).expect_success().commit();
+
+    approve_cep_78(
+        &mut builder,
+        seller,
+        cep78_hash,
+        upgraded_marketplace_hash.into(),
+        0,
+    );
+    let req = build_accept_offer_request(
+        seller,
+        upgraded_marketplace_hash,
+        cep78_hash,
+        U256::from(0),
+        offerer,
+        None,
+        None,
+        1,
+        2_000,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Auction of first version keeps extending by 10 minutes on late bids
+    let late_bid = bid * 2;
+    let req = create_place_bid_request(
+        offerer,
+        U256::from(2),
+        late_bid,
+        upgraded_marketplace_hash,
+        cep47_hash,
+        19 * 60_000,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let req = build_end_auction_request(
+        seller,
+        upgraded_marketplace_hash,
+        cep47_hash,
+        U256::from(2),
+        20 * 60_000 + 1_000,
+        0,
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_end_auction_request(
+        seller,
+        upgraded_marketplace_hash,
+        cep47_hash,
+        U256::from(2),
+        29 * 60_000 + 1_000,
+        0,
+    );
+    builder.exec(req).expect_success().commit();
+
+    let balance_seller_after = get_pending_balance(&mut builder, upgraded_marketplace_hash, seller);
+    assert_eq!(
+        listing_price + offer_amount + late_bid,
+        balance_seller_after - balance_seller_before
+    );
+}
+
+#[test]
+fn should_let_admin_cancel_dutch_auction_escrowed_before_upgrade() {
+    let (mut builder, marketplace_hash, _cep47_hash, cep78_hash, _marketplace_package_hash) =
+        get_default_fixture();
+
+    let installer = get_user(&mut builder, 0);
+    let buyer = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+    let price = U512::from(1_000_000_000_000u64);
+
+    approve_cep_78(&mut builder, seller, cep78_hash, marketplace_hash.into(), 0);
+
+    // Floor price nobody pays keeps token escrowed for good
+    let req = build_start_dutch_auction_request(
+        seller,
+        marketplace_hash,
+        cep78_hash,
+        U256::from(0),
+        1,
+        price * 2,
+        price,
+        10,
+        0,
+        1_000,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Escrowed token doesn't hold upgrade back
+    let upgraded_marketplace_hash = upgrade_marketplace(&mut builder);
+
+    let req = build_cancel_dutch_auction_request(
+        buyer,
+        upgraded_marketplace_hash,
+        cep78_hash,
+        U256::from(0),
+    );
+    builder.exec(req).expect_failure().commit();
+
+    let req = build_cancel_dutch_auction_request(
+        installer,
+        upgraded_marketplace_hash,
+        cep78_hash,
+        U256::from(0),
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Token went back to seller, who approves new contract hash to list it again
+    approve_cep_78(
+        &mut builder,
+        seller,
+        cep78_hash,
+        upgraded_marketplace_hash.into(),
+        0,
+    );
+    let req = create_listing(seller, upgraded_marketplace_hash, cep78_hash, 0, price, 0, 1);
+    builder.exec(req).expect_success().commit();
+}
+
+#[test]
+fn should_only_let_installer_migrate() {
+    let (mut builder, marketplace_hash, _cep47_hash, _cep78_hash, _marketplace_package_hash) =
+        get_default_fixture();
+
+    let user = get_user(&mut builder, 1);
+
+    let req = ExecuteRequestBuilder::contract_call_by_hash(
+        user,
+        marketplace_hash,
+        "migrate",
+        runtime_args! {
+            "previous_contract_hash" => marketplace_hash
+        },
+    )
+    .build();
+    builder.exec(req).expect_failure().commit();
+}
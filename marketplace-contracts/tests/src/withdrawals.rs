@@ -0,0 +1,111 @@
+use casper_types::{U256, U512};
+
+use crate::helpers::{
+    blockchain_helpers::{get_account_balance, get_event_names, get_pending_balance, get_user},
+    constants::MARKETPLACE_FEE_PERCENTAGE,
+    fixtures::get_listing_created_fixture,
+    marketplace_actions::{
+        build_deposit_request, build_get_pending_balance_request, build_withdraw_request,
+        create_buy_nft_request,
+    },
+};
+
+#[test]
+fn should_credit_proceeds_and_let_withdraw_them() {
+    let (
+        mut builder,
+        marketplace_hash,
+        cep47_hash,
+        _cep78_hash,
+        _marketplace_package_hash,
+        listing_price,
+        _listing_duration_minutes,
+    ) = get_listing_created_fixture();
+
+    let buyer = get_user(&mut builder, 1);
+    let seller = get_user(&mut builder, 2);
+
+    let req = create_buy_nft_request(
+        buyer,
+        U256::from(1),
+        marketplace_hash,
+        cep47_hash,
+        listing_price.as_u64(),
+        0,
+        0,
+    );
+    builder.exec(req).expect_success().commit();
+
+    // Seller is credited instead of paid out
+    let expected_proceeds =
+        listing_price * U512::from(100 - MARKETPLACE_FEE_PERCENTAGE) / U512::from(100);
+    assert_eq!(
+        get_pending_balance(&mut builder, marketplace_hash, seller),
+        expected_proceeds
+    );
+
+    let req = build_get_pending_balance_request(buyer, marketplace_hash, seller.into());
+    builder.exec(req).expect_success().commit();
+
+    // Can't withdraw more than credited
+    let req = build_withdraw_request(seller, marketplace_hash, Some(expected_proceeds + 1));
+    builder.exec(req).expect_failure().commit();
+
+    // Partial withdrawal
+    let part = expected_proceeds / 2;
+    let balance_before = get_account_balance(&mut builder, seller);
+    let req = build_withdraw_request(seller, marketplace_hash, Some(part));
+    builder.exec(req).expect_success().commit();
+
+    // Withdrawn amount outweighs the deploy payment
+    assert!(get_account_balance(&mut builder, seller) > balance_before);
+    assert_eq!(
+        get_pending_balance(&mut builder, marketplace_hash, seller),
+        expected_proceeds - part
+    );
+
+    // Withdraw the rest
+    let req = build_withdraw_request(seller, marketplace_hash, None);
+    builder.exec(req).expect_success().commit();
+    assert_eq!(
+        get_pending_balance(&mut builder, marketplace_hash, seller),
+        U512::zero()
+    );
+}
+
+#[test]
+fn should_credit_deposit_and_emit_event() {
+    let (
+        mut builder,
+        marketplace_hash,
+        _cep47_hash,
+        _cep78_hash,
+        _marketplace_package_hash,
+        _listing_price,
+        _listing_duration_minutes,
+    ) = get_listing_created_fixture();
+
+    let buyer = get_user(&mut builder, 1);
+    let amount = U512::from(5_000_000_000u64);
+
+    let req = build_deposit_request(buyer, marketplace_hash, amount);
+    builder.exec(req).expect_success().commit();
+
+    assert_eq!(get_pending_balance(&mut builder, marketplace_hash, buyer), amount);
+    assert_eq!(
+        get_event_names(&mut builder, marketplace_hash).last().map(String::as_str),
+        Some("event_FundsDeposited")
+    );
+
+    // Deposited funds can be withdrawn again
+    let req = build_withdraw_request(buyer, marketplace_hash, None);
+    builder.exec(req).expect_success().commit();
+    assert_eq!(
+        get_pending_balance(&mut builder, marketplace_hash, buyer),
+        U512::zero()
+    );
+    assert_eq!(
+        get_event_names(&mut builder, marketplace_hash).last().map(String::as_str),
+        Some("event_FundsWithdrawn")
+    );
+}